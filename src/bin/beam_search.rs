@@ -0,0 +1,64 @@
+use daap8::{
+    problem_solver::{BeamSearch, ProblemSolver},
+    ProblemInstance,
+};
+use std::fs::File;
+use std::io::{Result, Write};
+use std::time::Instant;
+
+fn main() -> Result<()> {
+    let mut output = File::create("result/beam_search.md")?;
+    print_headers(&mut output)?;
+    for (file, n, k) in [
+        ("problem_instances/max_div_15_2.txt", 15, 2),
+        ("problem_instances/max_div_20_2.txt", 20, 2),
+        ("problem_instances/max_div_30_2.txt", 30, 2),
+        ("problem_instances/max_div_15_3.txt", 15, 3),
+        ("problem_instances/max_div_20_3.txt", 20, 3),
+        ("problem_instances/max_div_30_3.txt", 30, 3),
+    ] {
+        let instance = match ProblemInstance::from_file(file) {
+            Ok(instance) => instance,
+            Err(err) => {
+                println!("{}: {}", file, err);
+                return Ok(());
+            }
+        };
+        for m in 2..=5 {
+            for beam_width in [1, 5, 20] {
+                write!(output, "|{}|{}|{}|{}|{}|", file, n, k, m, beam_width)?;
+                print_results(&mut output, &instance, m, beam_width)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn print_results(
+    output: &mut File,
+    instance: &ProblemInstance,
+    m: usize,
+    beam_width: usize,
+) -> Result<()> {
+    let mut solver = BeamSearch::new(m, beam_width);
+    let instant = Instant::now();
+    let solution = solver.solve(instance);
+    let duration = instant.elapsed();
+    writeln!(
+        output,
+        "{:.3}|{}|{}|{}|",
+        solution.get_z(),
+        solution,
+        duration.as_micros(),
+        solver.get_generated_nodes()
+    )?;
+    Ok(())
+}
+
+fn print_headers(output: &mut File) -> Result<()> {
+    writeln!(
+        output,
+        "|Problem|n|k|m|Beam width|z|S|CPU|number of generated nodes|"
+    )?;
+    writeln!(output, "|---|---|---|---|---|---|---|---|---|")
+}
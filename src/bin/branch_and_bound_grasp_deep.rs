@@ -9,7 +9,7 @@ use std::time::Instant;
 fn main() -> Result<()> {
     let mut output = File::create("result/branch_and_bound_grasp_deep.md")?;
     print_headers(&mut output)?;
-    for (file, n, k) in vec![
+    for (file, n, k) in [
         ("problem_instances/max_div_15_2.txt", 15, 2),
         ("problem_instances/max_div_20_2.txt", 20, 2),
         ("problem_instances/max_div_30_2.txt", 30, 2),
@@ -33,14 +33,14 @@ fn main() -> Result<()> {
 }
 
 fn print_results(output: &mut File, instance: &ProblemInstance, m: usize) -> Result<()> {
-    let mut initial_solver = GRASP::new(m, 1, Swap::new(), 10);
-    let mut solver = DeepBranchAndBound::new(&mut initial_solver);
+    let mut solver =
+        DeepBranchAndBound::new(m).with_initial_solver(GRASP::new(m, 1, Swap::new(), 10));
     let instant = Instant::now();
     let solution = solver.solve(instance);
     let duration = instant.elapsed();
-    write!(
+    writeln!(
         output,
-        "{:.3}|{}|{}|{}|\n",
+        "{:.3}|{}|{}|{}|",
         solution.get_z(),
         solution,
         duration.as_micros(),
@@ -50,9 +50,6 @@ fn print_results(output: &mut File, instance: &ProblemInstance, m: usize) -> Res
 }
 
 fn print_headers(output: &mut File) -> Result<()> {
-    write!(
-        output,
-        "|Problem|n|k|m|z|S|CPU|number of generated nodes|\n"
-    )?;
-    write!(output, "|---|---|---|---|---|---|---|---|\n")
+    writeln!(output, "|Problem|n|k|m|z|S|CPU|number of generated nodes|")?;
+    writeln!(output, "|---|---|---|---|---|---|---|---|")
 }
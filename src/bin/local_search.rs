@@ -12,7 +12,7 @@ use std::time::Instant;
 fn main() -> Result<()> {
     let mut output = File::create("result/local_search.md")?;
     print_headers(&mut output)?;
-    for (file, n, k) in vec![
+    for (file, n, k) in [
         ("problem_instances/max_div_15_2.txt", 15, 2),
         ("problem_instances/max_div_20_2.txt", 20, 2),
         ("problem_instances/max_div_30_2.txt", 30, 2),
@@ -41,9 +41,9 @@ fn print_results(output: &mut File, instance: &ProblemInstance, m: usize) -> Res
     let instant = Instant::now();
     let solution = search.improve(instance, solver.solve(instance));
     let duration = instant.elapsed();
-    write!(
+    writeln!(
         output,
-        "{:.3}|{}|{}|\n",
+        "{:.3}|{}|{}|",
         solution.get_z(),
         solution,
         duration.as_micros()
@@ -52,6 +52,6 @@ fn print_results(output: &mut File, instance: &ProblemInstance, m: usize) -> Res
 }
 
 fn print_headers(output: &mut File) -> Result<()> {
-    write!(output, "|Problem|n|k|m|z|S|CPU|\n")?;
-    write!(output, "|---|---|---|---|---|---|---|\n")
+    writeln!(output, "|Problem|n|k|m|z|S|CPU|")?;
+    writeln!(output, "|---|---|---|---|---|---|---|")
 }
@@ -9,7 +9,7 @@ use std::time::Instant;
 fn main() -> Result<()> {
     let mut output = File::create("result/tabu_search3.md")?;
     print_headers(&mut output)?;
-    for (file, n, k) in vec![
+    for (file, n, k) in [
         ("problem_instances/max_div_15_3.txt", 15, 3),
         ("problem_instances/max_div_20_3.txt", 20, 3),
         ("problem_instances/max_div_30_3.txt", 30, 3),
@@ -22,8 +22,8 @@ fn main() -> Result<()> {
             }
         };
         for m in 2..=5 {
-            for iter in vec![10, 20] {
-                for tabu_tenure in vec![2, 3] {
+            for iter in [10, 20] {
+                for tabu_tenure in [2, 3] {
                     write!(
                         output,
                         "|{}|{}|{}|{}|{}|{}|",
@@ -48,9 +48,9 @@ fn print_results(
     let instant = Instant::now();
     let solution = solver.solve(instance);
     let duration = instant.elapsed();
-    write!(
+    writeln!(
         output,
-        "{:.3}|{}|{}|\n",
+        "{:.3}|{}|{}|",
         solution.get_z(),
         solution,
         duration.as_micros()
@@ -59,6 +59,6 @@ fn print_results(
 }
 
 fn print_headers(output: &mut File) -> Result<()> {
-    write!(output, "|Problem|n|k|m|Iter|Tabu tenure|z|S|CPU|\n")?;
-    write!(output, "|---|---|---|---|---|---|---|---|---|\n")
+    writeln!(output, "|Problem|n|k|m|Iter|Tabu tenure|z|S|CPU|")?;
+    writeln!(output, "|---|---|---|---|---|---|---|---|---|")
 }
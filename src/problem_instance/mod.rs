@@ -1,22 +1,30 @@
 //! This module defines the class [ProblemInstance](ProblemInstance) which represents
 //! an instance of this problem.
+use std::collections::BTreeMap;
 use std::io::{BufRead, BufReader};
 use std::path::Path;
 use std::{fs::File, num::ParseFloatError};
 
 mod problem_instance_error;
-pub use problem_instance_error::{
-    ProblemInstanceError,
-    ProblemInstanceError::{IOError, SyntaxError},
-};
+pub use problem_instance_error::{ProblemInstanceError, ProblemInstanceError::SyntaxError};
 mod point;
 pub use point::Point;
 
 const SEPARATOR: &str = "\t";
 
-/// An instance of the problem. It is composed of a set of points of the same dimensionality
+/// An instance of the problem. It is composed of a set of points of the same dimensionality.
+/// Some of them can be fixed, so every solution must include them, or forbidden, so
+/// no solution can include them. It can also have a capacity, which limits the sum of
+/// the weights of the points of a solution, and limits on the number of points of
+/// each group a solution can have
 pub struct ProblemInstance {
     pub(super) points: Vec<Point>,
+    pub(super) fixed_points: Vec<usize>,
+    pub(super) forbidden_points: Vec<usize>,
+    fixed: Vec<bool>,
+    forbidden: Vec<bool>,
+    pub(super) capacity: Option<f64>,
+    pub(super) group_limits: BTreeMap<usize, (usize, usize)>,
 }
 
 impl ProblemInstance {
@@ -28,6 +36,13 @@ impl ProblemInstance {
     /// {dimensionality of the points}<br/>
     /// {A point with a coordinate for each dimension separated by tabs}<br/>
     /// Continues until all the points have been described<br/><br/>
+    /// A point can have an extra value after its coordinates, which is its weight,
+    /// and another one after it, which is its group. The weight must be finite and
+    /// not negative. The points without them weigh 1 and belong to the group 0<br/>
+    /// After the points the file can have a line with the capacity and a line with the
+    /// limits of each group:<br/>
+    /// capacity{tab}{capacity}<br/>
+    /// group{tab}{group}{tab}{minimum number of points}{tab}{maximum number of points}<br/>
     /// If the coordinates aren't integers you must use . as separator<br/>
     /// Keep in mind that two equal points shouldn't exist
     pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self, ProblemInstanceError> {
@@ -42,23 +57,321 @@ impl ProblemInstance {
         for i in 0..number_of_points {
             line.clear();
             file_reader.read_line(&mut line)?;
-            points.push(match ProblemInstance::parse_point(&line, SEPARATOR) {
-                Some(point) if point.get_dimensionality() == dimensionality => point,
-                _ => return Err(SyntaxError(i + 3)),
-            });
+            points.push(
+                ProblemInstance::parse_point(&line, SEPARATOR, dimensionality)
+                    .ok_or(SyntaxError(i + 3))?,
+            );
         }
-        Ok(ProblemInstance { points })
+        let mut instance = ProblemInstance::new(points);
+        let mut line_number = number_of_points + 2;
+        loop {
+            line.clear();
+            if file_reader.read_line(&mut line)? == 0 {
+                return Ok(instance);
+            }
+            line_number += 1;
+            instance = instance
+                .parse_constraint(&line, SEPARATOR)
+                .ok_or(SyntaxError(line_number))?;
+        }
+    }
+
+    /// Creates an instance from its points, without fixed or forbidden points
+    pub(crate) fn new(points: Vec<Point>) -> Self {
+        ProblemInstance {
+            fixed_points: Vec::new(),
+            forbidden_points: Vec::new(),
+            fixed: vec![false; points.len()],
+            forbidden: vec![false; points.len()],
+            points,
+            capacity: None,
+            group_limits: BTreeMap::new(),
+        }
+    }
+
+    /// Fixes the points with the specified indexes, so every solution must include
+    /// them. A point can't be fixed and forbidden at the same time. They are respected
+    /// by every solver
+    pub fn with_fixed_points(mut self, mut indexes: Vec<usize>) -> Self {
+        assert!(indexes
+            .iter()
+            .all(|index| *index < self.points.len() && !self.forbidden[*index]));
+        indexes.sort_unstable();
+        indexes.dedup();
+        self.fixed = vec![false; self.points.len()];
+        for index in &indexes {
+            self.fixed[*index] = true;
+        }
+        self.fixed_points = indexes;
+        self
+    }
+
+    /// Forbids the points with the specified indexes, so no solution can include
+    /// them. A point can't be fixed and forbidden at the same time
+    pub fn with_forbidden_points(mut self, mut indexes: Vec<usize>) -> Self {
+        assert!(indexes
+            .iter()
+            .all(|index| *index < self.points.len() && !self.fixed[*index]));
+        indexes.sort_unstable();
+        indexes.dedup();
+        self.forbidden = vec![false; self.points.len()];
+        for index in &indexes {
+            self.forbidden[*index] = true;
+        }
+        self.forbidden_points = indexes;
+        self
+    }
+
+    /// Limits the sum of the weights of the points of a solution. The capacity replaces
+    /// the number of points the solvers receive, so a solution can have any number of
+    /// points that fit. It is respected by every solver
+    pub fn with_capacity(mut self, capacity: f64) -> Self {
+        assert!(capacity >= 0.0);
+        self.capacity = Some(capacity);
+        self
+    }
+
+    /// Limits the number of points of the specified group a solution can have. It is
+    /// respected by every solver, and the swaps between groups are only performed
+    /// when both groups stay within their limits. Whether they can be satisfied is
+    /// checked by [is_feasible](ProblemInstance::is_feasible)
+    pub fn with_group_limits(mut self, group: usize, min_points: usize, max_points: usize) -> Self {
+        assert!(min_points <= max_points);
+        self.group_limits.insert(group, (min_points, max_points));
+        self
+    }
+
+    /// Gets the minimum and maximum number of points of the specified group a solution
+    /// can have
+    pub fn get_group_limits(&self, group: usize) -> (usize, usize) {
+        self.group_limits
+            .get(&group)
+            .copied()
+            .unwrap_or((0, usize::MAX))
+    }
+
+    /// Counts how many of the points belong to each group
+    pub(crate) fn get_group_counts(&self, points: &[Point]) -> BTreeMap<usize, usize> {
+        let mut counts = BTreeMap::new();
+        for point in points {
+            *counts.entry(point.group).or_insert(0) += 1;
+        }
+        counts
+    }
+
+    /// Whether a point of the specified group can be added to a solution with the
+    /// specified counts per group, so that the minimums can still be reached with the
+    /// specified number of points left to add after it
+    pub(crate) fn can_add_to_group(
+        &self,
+        counts: &BTreeMap<usize, usize>,
+        group: usize,
+        points_left: usize,
+    ) -> bool {
+        if self.group_limits.is_empty() {
+            return true;
+        }
+        let count = |other_group: usize| {
+            counts.get(&other_group).copied().unwrap_or(0) + (other_group == group) as usize
+        };
+        let missing_points = self
+            .group_limits
+            .iter()
+            .map(|(other_group, (min_points, _))| min_points.saturating_sub(count(*other_group)))
+            .sum::<usize>();
+        count(group) <= self.get_group_limits(group).1 && missing_points <= points_left
+    }
+
+    /// Whether the points of the leaving groups can be replaced with points of the
+    /// entering groups in a solution with the specified counts per group. A group
+    /// can't go out of its limits, or further from them if it already is
+    pub(crate) fn can_exchange_groups(
+        &self,
+        counts: &BTreeMap<usize, usize>,
+        leaving_groups: &[usize],
+        entering_groups: &[usize],
+    ) -> bool {
+        if self.group_limits.is_empty() {
+            return true;
+        }
+        leaving_groups.iter().chain(entering_groups).all(|group| {
+            let count = counts.get(group).copied().unwrap_or(0);
+            let new_count = count + entering_groups.iter().filter(|g| *g == group).count()
+                - leaving_groups.iter().filter(|g| *g == group).count();
+            let (min_points, max_points) = self.get_group_limits(*group);
+            (new_count >= min_points || new_count >= count)
+                && (new_count <= max_points || new_count <= count)
+        })
+    }
+
+    /// Gets the capacity of the instance, if it has one
+    pub fn get_capacity(&self) -> Option<f64> {
+        self.capacity
     }
 
-    fn parse_point(point_str: &str, separator: &str) -> Option<Point> {
-        point_str
+    /// Gets the maximum number of points of the solutions of a solver created with the
+    /// specified number of points. If the instance has a capacity it replaces that
+    /// number, so the solutions can have every point of the instance
+    pub(crate) fn get_max_points(&self, number_of_points: usize) -> usize {
+        match self.capacity {
+            Some(_) => self.points.len(),
+            None => number_of_points,
+        }
+    }
+
+    /// Whether a solution with the specified weight fits in the capacity
+    pub(crate) fn fits(&self, weight: f64) -> bool {
+        self.capacity.is_none_or(|capacity| weight <= capacity)
+    }
+
+    /// Gets the indexes of the points every solution must include
+    pub fn get_fixed_points(&self) -> &[usize] {
+        &self.fixed_points
+    }
+
+    /// Gets the indexes of the points no solution can include
+    pub fn get_forbidden_points(&self) -> &[usize] {
+        &self.forbidden_points
+    }
+
+    /// Whether the point with the specified index is one of the fixed points
+    pub(crate) fn is_fixed_index(&self, index: usize) -> bool {
+        self.fixed[index]
+    }
+
+    /// Whether the point with the specified index is one of the forbidden points
+    pub(crate) fn is_forbidden_index(&self, index: usize) -> bool {
+        self.forbidden[index]
+    }
+
+    /// Whether the point is one of the fixed points. It compares the point with each
+    /// of them, so when the index of the point is known
+    /// [is_fixed_index](ProblemInstance::is_fixed_index) should be used
+    pub(crate) fn is_fixed(&self, point: &Point) -> bool {
+        self.fixed_points
+            .iter()
+            .any(|index| self.points[*index] == *point)
+    }
+
+    /// Whether the point is one of the forbidden points. It compares the point with
+    /// each of them, so when the index of the point is known
+    /// [is_forbidden_index](ProblemInstance::is_forbidden_index) should be used
+    pub(crate) fn is_forbidden(&self, point: &Point) -> bool {
+        self.forbidden_points
+            .iter()
+            .any(|index| self.points[*index] == *point)
+    }
+
+    /// Whether a solution with the specified number of points can respect the fixed
+    /// and forbidden points, the capacity and the group limits. With a capacity the
+    /// number of points is replaced by it. Each group must have enough points that
+    /// aren't forbidden to reach its minimum and can't have more fixed points than its
+    /// maximum. The fixed points and the lightest points that reach the minimums must
+    /// fit in the number of points and in the capacity, and without a capacity the
+    /// groups must be able to take the number of points. The solvers panic with an
+    /// instance that isn't feasible, so it should be checked before solving one whose
+    /// constraints aren't known to be satisfiable
+    pub fn is_feasible(&self, number_of_points: usize) -> bool {
+        let number_of_points = self.get_max_points(number_of_points);
+        let mut counts = BTreeMap::new();
+        let mut fixed_counts = BTreeMap::new();
+        let mut available_weights = BTreeMap::new();
+        for (index, point) in self.points.iter().enumerate() {
+            if !self.forbidden[index] {
+                *counts.entry(point.group).or_insert(0) += 1;
+            }
+            if self.fixed[index] {
+                *fixed_counts.entry(point.group).or_insert(0) += 1;
+            } else if !self.forbidden[index] {
+                available_weights
+                    .entry(point.group)
+                    .or_insert_with(Vec::new)
+                    .push(point.weight);
+            }
+        }
+        let mut required_points = self.fixed_points.len();
+        let mut required_weight = self
+            .fixed_points
+            .iter()
+            .map(|index| self.points[*index].weight)
+            .sum::<f64>();
+        for (group, (min_points, max_points)) in &self.group_limits {
+            let fixed_count = fixed_counts.get(group).copied().unwrap_or(0);
+            let mut weights = available_weights.remove(group).unwrap_or_default();
+            let missing_points = min_points.saturating_sub(fixed_count);
+            if fixed_count > *max_points || weights.len() < missing_points {
+                return false;
+            }
+            weights.sort_by(|a, b| a.partial_cmp(b).unwrap());
+            required_points += missing_points;
+            required_weight += weights[..missing_points].iter().sum::<f64>();
+        }
+        let max_points = counts
+            .iter()
+            .map(|(group, count)| (*count).min(self.get_group_limits(*group).1))
+            .sum::<usize>();
+        required_points <= number_of_points
+            && self.fits(required_weight)
+            && (self.capacity.is_some() || max_points >= number_of_points)
+    }
+
+    /// Parses a point with the specified dimensionality. If the line has extra values
+    /// they are the weight and the group of the point
+    fn parse_point(point_str: &str, separator: &str, dimensionality: usize) -> Option<Point> {
+        let mut values = point_str
             .trim()
             .replace(",", ".")
             .split(separator)
             .map(|coordinate| coordinate.parse::<f64>())
             .collect::<Result<Vec<f64>, ParseFloatError>>()
-            .map(|coordinates| Point::new(coordinates))
-            .ok()
+            .ok()?;
+        if values.len() < dimensionality || values.len() > dimensionality + 2 {
+            return None;
+        }
+        let extra_values = values.split_off(dimensionality);
+        let mut point = Point::new(values);
+        if let Some(weight) = extra_values.first() {
+            if !weight.is_finite() || *weight < 0.0 {
+                return None;
+            }
+            point = point.with_weight(*weight);
+        }
+        if let Some(group) = extra_values.get(1) {
+            if *group < 0.0 || group.fract() != 0.0 {
+                return None;
+            }
+            point = point.with_group(*group as usize);
+        }
+        Some(point)
+    }
+
+    /// Applies a constraint given after the points of an instance file. The empty
+    /// lines are ignored
+    fn parse_constraint(self, constraint_str: &str, separator: &str) -> Option<Self> {
+        let constraint_str = constraint_str.trim().replace(",", ".");
+        if constraint_str.is_empty() {
+            return Some(self);
+        }
+        let mut values = constraint_str.split(separator);
+        let name = values.next()?;
+        let values = values
+            .map(|value| value.parse::<f64>())
+            .collect::<Result<Vec<f64>, ParseFloatError>>()
+            .ok()?;
+        let is_count = |value: &f64| *value >= 0.0 && value.fract() == 0.0;
+        match (name, values.as_slice()) {
+            ("capacity", [capacity]) if *capacity >= 0.0 => Some(self.with_capacity(*capacity)),
+            ("group", [group, min_points, max_points])
+                if values.iter().all(is_count) && min_points <= max_points =>
+            {
+                Some(self.with_group_limits(
+                    *group as usize,
+                    *min_points as usize,
+                    *max_points as usize,
+                ))
+            }
+            _ => None,
+        }
     }
 
     /// Allows to get the list of points
@@ -66,3 +379,60 @@ impl ProblemInstance {
         &self.points
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn constraints_from_file() {
+        let instance =
+            ProblemInstance::from_file("problem_instances/max_div_15_2_capacity.txt").unwrap();
+        assert_eq!(instance.points.len(), 15);
+        assert_eq!(instance.get_capacity(), Some(10.0));
+        let instance =
+            ProblemInstance::from_file("problem_instances/max_div_20_2_balanced.txt").unwrap();
+        assert_eq!(instance.points.len(), 20);
+        assert_eq!(instance.get_group_limits(0), (1, 2));
+        assert_eq!(instance.get_group_limits(1), (1, 1));
+        assert_eq!(instance.get_group_limits(2), (0, usize::MAX));
+        for constraint in [
+            "capacity",
+            "capacity\t-1",
+            "capacity\t1\t2",
+            "group\t0\t2\t1",
+            "group\t0\t0.5\t1",
+            "weight\t1",
+        ] {
+            let instance = ProblemInstance::new(Vec::new());
+            assert!(instance.parse_constraint(constraint, SEPARATOR).is_none());
+        }
+        for point in ["0\t0\t-1", "0\t0\tNaN", "0\t0\tinf", "0\t0\t-inf"] {
+            assert!(ProblemInstance::parse_point(point, SEPARATOR, 2).is_none());
+        }
+        assert!(ProblemInstance::parse_point("0\t0\t1\t2", SEPARATOR, 2).is_some());
+    }
+    #[test]
+    fn unsatisfiable_group_limits() {
+        let instance =
+            || ProblemInstance::from_file("problem_instances/max_div_20_2_grouped.txt").unwrap();
+        // The groups of the points cycle from 0 to 2
+        assert!(instance().with_group_limits(1, 1, 1).is_feasible(4));
+        assert!(!instance().with_group_limits(1, 5, 5).is_feasible(4));
+        let forbidden_group = instance()
+            .with_forbidden_points(vec![1, 4, 7, 10, 13, 16, 19])
+            .with_group_limits(1, 1, 1);
+        assert!(!forbidden_group.is_feasible(4));
+        let fixed_group = instance()
+            .with_fixed_points(vec![1, 4])
+            .with_group_limits(1, 0, 1);
+        assert!(!fixed_group.is_feasible(4));
+        let small_groups = instance()
+            .with_group_limits(0, 0, 1)
+            .with_group_limits(1, 0, 1)
+            .with_group_limits(2, 0, 1);
+        assert!(small_groups.is_feasible(3));
+        assert!(!small_groups.is_feasible(4));
+        let heavy_minimum = instance().with_capacity(1.0).with_group_limits(1, 2, 2);
+        assert!(!heavy_minimum.is_feasible(4));
+    }
+}
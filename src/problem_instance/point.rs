@@ -1,15 +1,46 @@
 use std::cmp::{Eq, PartialEq};
 
-/// Represents a point in a n dimensional space
+/// Represents a point in a n dimensional space. It also has a weight, which is 1 by
+/// default and only matters when the instance has a capacity, and a group, which is
+/// 0 by default and only matters when the instance limits the points of its group
 #[derive(Debug, Clone)]
 pub struct Point {
     pub(crate) coordinates: Vec<f64>,
+    pub(crate) weight: f64,
+    pub(crate) group: usize,
 }
 
 impl Point {
     /// Creates a new point from its coordinates
     pub fn new(coordinates: Vec<f64>) -> Point {
-        Point { coordinates }
+        Point {
+            coordinates,
+            weight: 1.0,
+            group: 0,
+        }
+    }
+
+    /// Sets the weight of the point, which can't be negative
+    pub fn with_weight(mut self, weight: f64) -> Point {
+        assert!(weight >= 0.0);
+        self.weight = weight;
+        self
+    }
+
+    /// Gets the weight of the point
+    pub fn get_weight(&self) -> f64 {
+        self.weight
+    }
+
+    /// Sets the group of the point
+    pub fn with_group(mut self, group: usize) -> Point {
+        self.group = group;
+        self
+    }
+
+    /// Gets the group of the point
+    pub fn get_group(&self) -> usize {
+        self.group
     }
 
     /// Gets the dimensionality of the point
@@ -45,32 +76,20 @@ mod tests {
     use super::*;
     #[test]
     fn euclidean_distance() {
-        let point1 = Point {
-            coordinates: vec![2.0, 3.0, 4.0],
-        };
-        let point2 = Point {
-            coordinates: vec![3.0, 1.0, 2.0],
-        };
+        let point1 = Point::new(vec![2.0, 3.0, 4.0]);
+        let point2 = Point::new(vec![3.0, 1.0, 2.0]);
         assert_eq!(point1.distance_to(&point2), 3.0);
     }
     #[test]
     fn equality() {
-        let point1 = Point {
-            coordinates: vec![2.0, 3.0, 4.0],
-        };
-        let point2 = Point {
-            coordinates: vec![2.0, 3.0, 4.0],
-        };
+        let point1 = Point::new(vec![2.0, 3.0, 4.0]);
+        let point2 = Point::new(vec![2.0, 3.0, 4.0]);
         assert!(point1 == point2);
     }
     #[test]
     fn false_equality() {
-        let point1 = Point {
-            coordinates: vec![2.0, 3.0, 4.0],
-        };
-        let point2 = Point {
-            coordinates: vec![3.0, 1.0, 2.0],
-        };
+        let point1 = Point::new(vec![2.0, 3.0, 4.0]);
+        let point2 = Point::new(vec![3.0, 1.0, 2.0]);
         assert!(point1 != point2);
     }
 }
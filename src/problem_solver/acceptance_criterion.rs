@@ -0,0 +1,54 @@
+/// The criterion an [iterated local search](super::ILS) uses to decide if it moves
+/// to the solution found after the perturbation and the local search
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum AcceptanceCriterion {
+    /// Only moves to the new solution if it is better than the actual one
+    #[default]
+    Better,
+    /// Always moves to the new solution
+    RandomWalk,
+    /// Always moves to a better solution and moves to a worse one with probability
+    /// e^(delta / temperature), like a simulated annealing. The temperature starts
+    /// with the specified value and is multiplied by the cooling rate after each
+    /// iteration, which must be between 0 and 1
+    SimulatedAnnealing {
+        initial_temperature: f64,
+        cooling_rate: f64,
+    },
+}
+
+impl AcceptanceCriterion {
+    /// Gets the temperature the search starts with. It is only meaningful for the
+    /// simulated annealing criterion
+    pub(super) fn get_initial_temperature(&self) -> f64 {
+        match self {
+            AcceptanceCriterion::SimulatedAnnealing {
+                initial_temperature,
+                ..
+            } => *initial_temperature,
+            _ => 0.0,
+        }
+    }
+
+    /// Gets the temperature after an iteration with the given temperature
+    pub(super) fn cool(&self, temperature: f64) -> f64 {
+        match self {
+            AcceptanceCriterion::SimulatedAnnealing { cooling_rate, .. } => {
+                temperature * cooling_rate
+            }
+            _ => temperature,
+        }
+    }
+
+    /// Whether a solution whose z differs from the actual one by delta is accepted.
+    /// The random value must be uniformly distributed in [0, 1)
+    pub(super) fn accepts(&self, delta: f64, temperature: f64, random_value: f64) -> bool {
+        match self {
+            AcceptanceCriterion::Better => delta > 0.0,
+            AcceptanceCriterion::RandomWalk => true,
+            AcceptanceCriterion::SimulatedAnnealing { .. } => {
+                delta > 0.0 || (temperature > 0.0 && random_value < (delta / temperature).exp())
+            }
+        }
+    }
+}
@@ -0,0 +1,113 @@
+use super::{
+    get_new_partial_solution, get_possible_points_to_add, BranchingOrder, PartialSolution,
+    ProblemInstance, ProblemSolution, ProblemSolver,
+};
+
+/// A beam search algorithm. It builds the solutions level by level using the same
+/// search tree as [BranchAndBound](super::BranchAndBound), but in each level it only
+/// keeps the nodes with the greatest upper bound. The number of nodes kept is the
+/// beam width, so a width of 1 behaves like a greedy algorithm and a big enough
+/// width explores the whole tree
+pub struct BeamSearch {
+    number_of_points: usize,
+    beam_width: usize,
+    branching_order: BranchingOrder,
+    generated_nodes: usize,
+}
+
+impl ProblemSolver for BeamSearch {
+    fn solve(&mut self, instance: &ProblemInstance) -> ProblemSolution {
+        assert!(instance.is_feasible(self.number_of_points));
+        self.generated_nodes = 0;
+        let number_of_points = instance.get_max_points(self.number_of_points);
+        let points = self.branching_order.sort_points(instance);
+        let mut beam = vec![PartialSolution::root(instance, number_of_points)];
+        let is_solution = |partial_solution: &PartialSolution| {
+            partial_solution.solution.points.len() == number_of_points
+                || instance.get_capacity().is_some()
+                    && partial_solution
+                        .solution
+                        .is_feasible(instance, number_of_points)
+        };
+        let mut best_solution = Some(beam[0].solution.clone()).filter(|_| is_solution(&beam[0]));
+        for _ in instance.get_fixed_points().len()..number_of_points {
+            let mut candidates = Vec::new();
+            for partial_solution in &beam {
+                let possible_indexes = get_possible_points_to_add(
+                    &points,
+                    partial_solution,
+                    instance,
+                    number_of_points,
+                );
+                self.generated_nodes += possible_indexes.len();
+                candidates.extend(possible_indexes.into_iter().map(|index| {
+                    get_new_partial_solution(
+                        partial_solution,
+                        index,
+                        &points,
+                        instance,
+                        number_of_points,
+                    )
+                }));
+            }
+            // With a capacity every node is a solution, and a level can be empty
+            // if no point fits
+            let best_candidate = candidates
+                .iter()
+                .filter(|partial_solution| is_solution(partial_solution))
+                .map(|partial_solution| &partial_solution.solution)
+                .chain(best_solution.iter())
+                .reduce(|max, actual| {
+                    if actual.get_z() > max.get_z() {
+                        return actual;
+                    }
+                    max
+                })
+                .cloned();
+            best_solution = best_candidate;
+            candidates.sort_by(|a, b| b.upper_bound.partial_cmp(&a.upper_bound).unwrap());
+            candidates.truncate(self.beam_width);
+            beam = candidates;
+        }
+        // The last level has complete solutions, or every feasible node is a solution
+        best_solution.unwrap()
+    }
+}
+
+impl BeamSearch {
+    /// Creates a new beam search. The number of points the solution will have and
+    /// the beam width need to be passed as arguments
+    pub fn new(number_of_points: usize, beam_width: usize) -> Self {
+        assert!(number_of_points > 0 && beam_width > 0);
+        BeamSearch {
+            number_of_points,
+            beam_width,
+            branching_order: BranchingOrder::default(),
+            generated_nodes: 0,
+        }
+    }
+
+    /// Sets the order in which the points are considered when branching.
+    /// By default the order of the instance is used
+    pub fn with_branching_order(mut self, branching_order: BranchingOrder) -> Self {
+        self.branching_order = branching_order;
+        self
+    }
+
+    pub fn get_generated_nodes(&self) -> usize {
+        self.generated_nodes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::BranchAndBound;
+    use super::*;
+    #[test]
+    fn wide_beam_search_is_exact() {
+        let instance = ProblemInstance::from_file("problem_instances/max_div_15_2.txt").unwrap();
+        let optimum = BranchAndBound::new(3).solve(&instance);
+        let solution = BeamSearch::new(3, 1000).solve(&instance);
+        assert!((solution.get_z() - optimum.get_z()).abs() < 1e-9);
+    }
+}
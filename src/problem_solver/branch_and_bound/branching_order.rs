@@ -0,0 +1,107 @@
+use super::{Point, ProblemInstance};
+use std::fmt;
+use std::str::FromStr;
+
+/// The order in which the branch and bound algorithms consider the points of an
+/// instance. Each node of the search tree can only add points that come after the
+/// last point it added, so the order decides which subsets are explored first
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum BranchingOrder {
+    /// The points are considered in the same order they have in the instance
+    #[default]
+    FileOrder,
+    /// The points are sorted by increasing sum of the distances to every other
+    /// point. The nodes that can add the first points are the ones with the
+    /// biggest subtrees, so this makes them have the smallest upper bounds and be
+    /// pruned earlier
+    IncreasingTotalDistance,
+    /// The points are sorted by decreasing sum of the distances to every other
+    /// point, so the ones that are more likely to be in a good solution are added
+    /// first and good lower bounds are found sooner
+    DecreasingTotalDistance,
+}
+
+impl BranchingOrder {
+    /// Gets the points of the instance the search can branch on sorted in this order.
+    /// The fixed points are left out because every node has them, and the forbidden
+    /// ones because no node can add them
+    pub(super) fn sort_points(&self, instance: &ProblemInstance) -> Vec<Point> {
+        self.sort_indexes(instance)
+            .into_iter()
+            .filter(|index| {
+                !instance.is_fixed_index(*index) && !instance.is_forbidden_index(*index)
+            })
+            .map(|index| instance.points[index].clone())
+            .collect()
+    }
+
+    /// Gets the indexes of all the points of the instance sorted in this order
+    fn sort_indexes(&self, instance: &ProblemInstance) -> Vec<usize> {
+        match self {
+            BranchingOrder::FileOrder => (0..instance.points.len()).collect(),
+            BranchingOrder::IncreasingTotalDistance => {
+                let mut indexes = BranchingOrder::DecreasingTotalDistance.sort_indexes(instance);
+                indexes.reverse();
+                indexes
+            }
+            BranchingOrder::DecreasingTotalDistance => {
+                let mut indexes = instance
+                    .points
+                    .iter()
+                    .enumerate()
+                    .map(|(index, point)| {
+                        let total_distance = instance
+                            .points
+                            .iter()
+                            .map(|other_point| point.distance_to(other_point))
+                            .sum::<f64>();
+                        (index, total_distance)
+                    })
+                    .collect::<Vec<(usize, f64)>>();
+                indexes.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap());
+                indexes.into_iter().map(|(index, _)| index).collect()
+            }
+        }
+    }
+}
+
+impl fmt::Display for BranchingOrder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl FromStr for BranchingOrder {
+    type Err = ();
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        match string {
+            "FileOrder" => Ok(BranchingOrder::FileOrder),
+            "IncreasingTotalDistance" => Ok(BranchingOrder::IncreasingTotalDistance),
+            "DecreasingTotalDistance" => Ok(BranchingOrder::DecreasingTotalDistance),
+            _ => Err(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn decreasing_total_distance() {
+        let instance = ProblemInstance::new(vec![
+            Point::new(vec![1.0, 0.0]),
+            Point::new(vec![0.0, 0.0]),
+            Point::new(vec![5.0, 0.0]),
+        ]);
+        let points = BranchingOrder::DecreasingTotalDistance.sort_points(&instance);
+        assert_eq!(
+            points,
+            vec![
+                Point::new(vec![5.0, 0.0]),
+                Point::new(vec![0.0, 0.0]),
+                Point::new(vec![1.0, 0.0]),
+            ]
+        );
+    }
+}
@@ -0,0 +1,14 @@
+/// What a branch and bound algorithm should do when the open nodes exceed the
+/// configured node or memory limits
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum DegradationMode {
+    /// Expands the deepest node until the open nodes are within the limits again.
+    /// The search stays exact, but the nodes are no longer chosen with the
+    /// usual criterion
+    #[default]
+    DepthFirst,
+    /// Keeps only the specified number of open nodes, the ones with the greatest
+    /// upper bound, and discards the rest. The solution can't be proven optimal
+    /// if any of the discarded nodes could have improved it
+    Beam(usize),
+}
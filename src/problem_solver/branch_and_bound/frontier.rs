@@ -0,0 +1,126 @@
+use super::super::checkpoint::{
+    solution_to_line, write_checkpoint, CheckpointError, CheckpointReader,
+};
+use super::{BranchingOrder, PartialSolution, ProblemInstance, ProblemSolution};
+use std::path::Path;
+
+/// The state of a branch and bound search. It contains the best solution found,
+/// the nodes that are still open and the greatest upper bound of the nodes that
+/// were discarded. A search stopped by a limit leaves its frontier behind, so it
+/// can be passed to a new search to continue from where the previous one stopped.
+/// It can also be saved to a file, so the search can be continued by another
+/// process, and a search with checkpoints saves it periodically
+pub struct Frontier {
+    pub(super) best_solution: ProblemSolution,
+    pub(super) partial_solutions: Vec<PartialSolution>,
+    pub(super) discarded_upper_bound: f64,
+    pub(super) branching_order: BranchingOrder,
+    pub(super) generated_nodes: usize,
+}
+
+impl Frontier {
+    /// Creates the frontier of a new search, which only has the root node open
+    pub(super) fn new(
+        instance: &ProblemInstance,
+        best_solution: ProblemSolution,
+        number_of_points: usize,
+        branching_order: BranchingOrder,
+    ) -> Self {
+        let root = PartialSolution::root(instance, number_of_points);
+        // When every point is fixed the root is the only solution, and the initial
+        // solution must already be it
+        let partial_solutions = if root.solution.points.len() < number_of_points {
+            vec![root]
+        } else {
+            Vec::new()
+        };
+        Frontier {
+            best_solution,
+            partial_solutions,
+            discarded_upper_bound: f64::NEG_INFINITY,
+            branching_order,
+            generated_nodes: 0,
+        }
+    }
+
+    /// Loads a frontier from a file created with [save](Frontier::save). The
+    /// instance must be the same one the search was solving
+    pub fn load<P: AsRef<Path>>(
+        path: P,
+        instance: &ProblemInstance,
+    ) -> Result<Self, CheckpointError> {
+        let mut reader = CheckpointReader::from_file(path, instance)?;
+        let branching_order = reader.read_value::<BranchingOrder>()?;
+        let generated_nodes = reader.read_value::<usize>()?;
+        let discarded_upper_bound = reader.read_value::<f64>()?;
+        let best_solution = reader.read_solution()?;
+        let number_of_partial_solutions = reader.read_value::<usize>()?;
+        let mut partial_solutions = Vec::with_capacity(number_of_partial_solutions);
+        for _ in 0..number_of_partial_solutions {
+            let next_index = reader.read_value::<usize>()?;
+            let upper_bound = reader.read_value::<f64>()?;
+            let solution = reader.read_solution()?;
+            partial_solutions.push(PartialSolution {
+                solution,
+                upper_bound,
+                next_index,
+            });
+        }
+        Ok(Frontier {
+            best_solution,
+            partial_solutions,
+            discarded_upper_bound,
+            branching_order,
+            generated_nodes,
+        })
+    }
+
+    /// Saves the frontier to a file, so the search can be continued later
+    pub fn save<P: AsRef<Path>>(
+        &self,
+        path: P,
+        instance: &ProblemInstance,
+    ) -> Result<(), CheckpointError> {
+        write_checkpoint(path, self.to_checkpoint(instance))
+    }
+
+    /// Gets the contents of the file that represents this frontier. The upper
+    /// bounds are saved too, so they don't need to be calculated again
+    pub(super) fn to_checkpoint(&self, instance: &ProblemInstance) -> String {
+        let mut lines = vec![
+            self.branching_order.to_string(),
+            self.generated_nodes.to_string(),
+            self.discarded_upper_bound.to_string(),
+            solution_to_line(&self.best_solution, instance),
+            self.partial_solutions.len().to_string(),
+        ];
+        for partial_solution in &self.partial_solutions {
+            lines.push(partial_solution.next_index.to_string());
+            lines.push(partial_solution.upper_bound.to_string());
+            lines.push(solution_to_line(&partial_solution.solution, instance));
+        }
+        lines.join("\n") + "\n"
+    }
+
+    /// Whether the whole search tree has already been explored
+    pub fn is_exhausted(&self) -> bool {
+        self.partial_solutions.is_empty()
+    }
+
+    /// The number of open nodes
+    pub fn get_open_nodes(&self) -> usize {
+        self.partial_solutions.len()
+    }
+
+    /// The best solution found so far
+    pub fn get_best_solution(&self) -> &ProblemSolution {
+        &self.best_solution
+    }
+
+    /// Replaces the best solution if the given one is better
+    pub(super) fn offer_solution(&mut self, solution: ProblemSolution) {
+        if solution.get_z() > self.best_solution.get_z() {
+            self.best_solution = solution;
+        }
+    }
+}
@@ -1,67 +1,452 @@
-use super::{Point, ProblemInstance, ProblemSolution, ProblemSolver};
+use super::checkpoint::{Checkpoint, CheckpointError};
+use super::{GreedySolver, Incumbent, Point, ProblemInstance, ProblemSolution, ProblemSolver};
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+/// Implements the configuration and the results shared by the branch and bound
+/// algorithms for a type that stores them in a `search` field
+macro_rules! impl_search_config {
+    ($solver:ident) => {
+        impl<'a> $solver<'a> {
+            /// Uses the given solution as the initial lower bound, unless a better one is
+            /// found by the initial solvers
+            pub fn with_initial_solution(mut self, solution: ProblemSolution) -> Self {
+                self.search.initial_solution = Some(solution);
+                self
+            }
+
+            /// Adds a [solver](super::ProblemSolver) that will be run before the search to
+            /// get the initial lower bound. Several solvers can be added and the best
+            /// solution among them will be used
+            pub fn with_initial_solver<S: ProblemSolver + Send + 'a>(mut self, solver: S) -> Self {
+                self.search.initial_solvers.push(Box::new(solver));
+                self
+            }
+
+            /// Continues the search from the [frontier](Frontier) left by a previous one
+            pub fn with_frontier(mut self, frontier: Frontier) -> Self {
+                self.search.frontier = Some(frontier);
+                self
+            }
+
+            /// Shares the best solution with other solvers through an
+            /// [incumbent](super::Incumbent). The search uses its solution as the lower
+            /// bound when it is better, offers the solutions it finds and stops with a
+            /// truncated certificate when the incumbent is stopped
+            pub fn with_incumbent(mut self, incumbent: Incumbent) -> Self {
+                self.search.incumbent = Some(incumbent);
+                self
+            }
+
+            /// Saves the [frontier](Frontier) of the search to the specified file each time
+            /// the specified number of nodes has been generated, so it can be
+            /// [loaded](Frontier::load) and continued if the search is interrupted
+            pub fn with_checkpoint<P: AsRef<Path>>(mut self, path: P, interval: usize) -> Self {
+                self.search.checkpoint = Some(Checkpoint::new(path, interval));
+                self
+            }
+
+            /// Stops the search once the specified number of nodes has been generated
+            pub fn with_node_limit(mut self, node_limit: usize) -> Self {
+                self.search.options.node_limit = Some(node_limit);
+                self
+            }
+
+            /// Stops the search once the specified amount of time has passed
+            pub fn with_time_limit(mut self, time_limit: Duration) -> Self {
+                self.search.options.time_limit = Some(time_limit);
+                self
+            }
+
+            /// Limits the number of open nodes. When there are more, the search
+            /// changes its behaviour according to the [degradation mode](DegradationMode)
+            pub fn with_open_node_limit(mut self, open_node_limit: usize) -> Self {
+                self.search.options.open_node_limit = Some(open_node_limit);
+                self
+            }
+
+            /// Limits the memory in bytes used by the open nodes. When they use more,
+            /// the search changes its behaviour according to the
+            /// [degradation mode](DegradationMode)
+            pub fn with_memory_limit(mut self, memory_limit: usize) -> Self {
+                self.search.options.memory_limit = Some(memory_limit);
+                self
+            }
+
+            /// Sets the order in which the points are considered when branching.
+            /// By default the order of the instance is used. A search continued from
+            /// a frontier keeps the order of the search that created it
+            pub fn with_branching_order(mut self, branching_order: BranchingOrder) -> Self {
+                self.search.options.branching_order = branching_order;
+                self
+            }
+
+            /// The total number of nodes generated, including the ones generated by the
+            /// searches that created the frontier this search was continued from
+            pub fn get_generated_nodes(&self) -> usize {
+                self.search.generated_nodes
+            }
+
+            /// Gets the [certificate](OptimalityCertificate) of the last solution found.
+            /// It will be None if the problem hasn't been solved yet
+            pub fn get_certificate(&self) -> Option<OptimalityCertificate> {
+                self.search.certificate
+            }
+
+            /// Takes the [frontier](Frontier) left by the last search, so it can be
+            /// continued later. It will be None if the problem hasn't been solved yet
+            pub fn take_frontier(&mut self) -> Option<Frontier> {
+                self.search.remaining_frontier.take()
+            }
+
+            /// Gets the last error that happened while saving a checkpoint. The search
+            /// doesn't stop when a checkpoint can't be saved
+            pub fn get_checkpoint_error(&self) -> Option<&CheckpointError> {
+                self.search
+                    .checkpoint
+                    .as_ref()
+                    .and_then(|checkpoint| checkpoint.get_error())
+            }
+        }
+    };
+}
+
 mod partial_solution;
 use partial_solution::PartialSolution;
 mod deep_branch_and_bound;
 pub use deep_branch_and_bound::DeepBranchAndBound;
+mod optimality_certificate;
+pub use optimality_certificate::OptimalityCertificate;
+mod degradation_mode;
+pub use degradation_mode::DegradationMode;
+mod branching_order;
+pub use branching_order::BranchingOrder;
+mod frontier;
+pub use frontier::Frontier;
+mod beam_search;
+pub use beam_search::BeamSearch;
 
-/// A implementation of a branch and bound algorithm.
-pub struct BranchAndBound<'a, S: ProblemSolver> {
-    solver: &'a mut S,
-    generated_nodes: usize,
+use deep_branch_and_bound::get_deepest_partial_solution;
+
+/// A implementation of a branch and bound algorithm. It chooses the node with
+/// the lowest upper bound in each iteration
+pub struct BranchAndBound<'a> {
+    search: Search<'a>,
 }
 
-impl<'a, S: ProblemSolver> ProblemSolver for BranchAndBound<'a, S> {
+impl<'a> ProblemSolver for BranchAndBound<'a> {
     fn solve(&mut self, instance: &ProblemInstance) -> ProblemSolution {
-        self.generated_nodes = 0;
-        let mut best_solution = self.solver.solve(instance);
-        let number_of_points = best_solution.points.len();
-        let mut lower_bound = best_solution.get_z();
-        let mut partial_solutions = vec![PartialSolution::new(
-            ProblemSolution { points: Vec::new() },
+        self.search
+            .solve(instance, get_partial_solution_with_smallest_upper_bound)
+    }
+}
+
+impl<'a> BranchAndBound<'a> {
+    /// Creates a new instance that will search for solutions with the specified
+    /// number of points. If no initial solution, solver or frontier is given, a
+    /// [greedy solver](super::GreedySolver) will be used to get the initial lower bound
+    pub fn new(number_of_points: usize) -> Self {
+        BranchAndBound {
+            search: Search::new(number_of_points),
+        }
+    }
+
+    /// Sets what the search should do when the open nodes exceed the limits.
+    /// By default it will change to a depth first search
+    pub fn with_degradation_mode(mut self, degradation_mode: DegradationMode) -> Self {
+        if let DegradationMode::Beam(width) = degradation_mode {
+            assert!(width > 0);
+        }
+        self.search.options.degradation_mode = degradation_mode;
+        self
+    }
+}
+
+impl_search_config!(BranchAndBound);
+
+/// The configuration shared by the branch and bound algorithms and the results
+/// of their last search
+struct Search<'a> {
+    number_of_points: usize,
+    initial_solvers: Vec<Box<dyn ProblemSolver + Send + 'a>>,
+    initial_solution: Option<ProblemSolution>,
+    frontier: Option<Frontier>,
+    options: SearchOptions,
+    checkpoint: Option<Checkpoint>,
+    incumbent: Option<Incumbent>,
+    generated_nodes: usize,
+    certificate: Option<OptimalityCertificate>,
+    remaining_frontier: Option<Frontier>,
+}
+
+impl<'a> Search<'a> {
+    fn new(number_of_points: usize) -> Self {
+        assert!(number_of_points > 0);
+        Search {
+            number_of_points,
+            initial_solvers: Vec::new(),
+            initial_solution: None,
+            frontier: None,
+            options: SearchOptions::default(),
+            checkpoint: None,
+            incumbent: None,
+            generated_nodes: 0,
+            certificate: None,
+            remaining_frontier: None,
+        }
+    }
+
+    /// Performs the search extracting the nodes to expand with the passed function
+    /// and stores the results
+    fn solve(
+        &mut self,
+        instance: &ProblemInstance,
+        select_partial_solution: fn(&mut Vec<PartialSolution>) -> PartialSolution,
+    ) -> ProblemSolution {
+        assert!(instance.is_feasible(self.number_of_points));
+        let number_of_points = instance.get_max_points(self.number_of_points);
+        let mut frontier = self.get_initial_frontier(instance, number_of_points);
+        let certificate = explore(
             instance,
+            &mut frontier,
+            &self.options,
+            self.checkpoint.as_mut(),
+            self.incumbent.as_ref(),
             number_of_points,
-        )];
-        while !partial_solutions.is_empty() {
-            let partial_solution =
-                get_partial_solution_with_smallest_upper_bound(&mut partial_solutions);
-            let possible_points =
-                get_possible_points_to_add(instance, &partial_solution, number_of_points);
-            self.generated_nodes += possible_points.len();
-            for point in possible_points {
-                let partial_solution =
-                    get_new_partial_solution(&partial_solution, point, instance, number_of_points);
-                if partial_solution.solution.points.len() == number_of_points {
-                    if partial_solution.upper_bound > lower_bound {
-                        lower_bound = partial_solution.upper_bound;
-                        best_solution = partial_solution.solution;
-                    }
-                } else if partial_solution.upper_bound > lower_bound {
-                    partial_solutions.push(partial_solution);
-                }
+            select_partial_solution,
+        );
+        let solution = frontier.best_solution.clone();
+        self.generated_nodes = frontier.generated_nodes;
+        self.certificate = Some(certificate);
+        self.remaining_frontier = Some(frontier);
+        solution
+    }
+
+    /// Gets the frontier the search starts from. It is the frontier passed to
+    /// continue a previous search or a new one, and its best solution gets
+    /// replaced by the initial solution or the ones of the initial solvers if
+    /// they are better. The solutions that aren't feasible are ignored
+    fn get_initial_frontier(
+        &mut self,
+        instance: &ProblemInstance,
+        number_of_points: usize,
+    ) -> Frontier {
+        let mut solutions = self
+            .initial_solution
+            .iter()
+            .cloned()
+            .chain(
+                self.initial_solvers
+                    .iter_mut()
+                    .map(|solver| solver.solve(instance)),
+            )
+            .collect::<Vec<ProblemSolution>>();
+        solutions.retain(|solution| solution.is_feasible(instance, number_of_points));
+        let mut frontier = match self.frontier.take() {
+            Some(frontier) => frontier,
+            None => {
+                let best_solution = if solutions.is_empty() {
+                    GreedySolver::new(self.number_of_points).solve(instance)
+                } else {
+                    solutions.remove(0)
+                };
+                Frontier::new(
+                    instance,
+                    best_solution,
+                    number_of_points,
+                    self.options.branching_order,
+                )
             }
-            partial_solutions = prune(partial_solutions, lower_bound);
+        };
+        assert!(frontier
+            .best_solution
+            .is_feasible(instance, number_of_points));
+        for solution in solutions {
+            frontier.offer_solution(solution);
         }
-        best_solution
+        frontier
     }
 }
 
-impl<'a, S: ProblemSolver> BranchAndBound<'a, S> {
-    /// Creates a new instance with the specified arguments. A [solver](super::ProblemSolver)
-    /// needs to be passed as argument. It will be used to get the initial lower bound. The
-    /// number of points in the solution will be infered from the solution given from the
-    /// solver
-    pub fn new(solver: &'a mut S) -> Self {
-        BranchAndBound {
-            solver,
-            generated_nodes: 0,
-        }
+/// The options of a branch and bound search. The node and time limits stop the
+/// search before the whole search tree has been explored, while the open node
+/// and memory limits make it degrade as specified by the degradation mode
+#[derive(Clone, Copy, Default)]
+struct SearchOptions {
+    node_limit: Option<usize>,
+    time_limit: Option<Duration>,
+    open_node_limit: Option<usize>,
+    memory_limit: Option<usize>,
+    degradation_mode: DegradationMode,
+    branching_order: BranchingOrder,
+}
+
+impl SearchOptions {
+    /// Checks if any of the limits has been reached
+    fn reached(&self, generated_nodes: usize, start: Instant) -> bool {
+        self.node_limit
+            .is_some_and(|node_limit| generated_nodes >= node_limit)
+            || self
+                .time_limit
+                .is_some_and(|time_limit| start.elapsed() >= time_limit)
     }
 
-    pub fn get_generated_nodes(&self) -> usize {
-        self.generated_nodes
+    /// Checks if the open nodes exceed the open node or the memory limit, given
+    /// their number and the memory they use
+    fn exceeded_by(&self, open_nodes: usize, memory_usage: usize) -> bool {
+        self.open_node_limit
+            .is_some_and(|open_node_limit| open_nodes > open_node_limit)
+            || self
+                .memory_limit
+                .is_some_and(|memory_limit| memory_usage > memory_limit)
     }
 }
 
+/// Performs the branch and bound search from the given frontier, using its best
+/// solution as the lower bound. In each iteration the node to expand is extracted
+/// from the open nodes with the passed function, unless the open nodes exceed the
+/// limits, in which case the search degrades as specified by the options. The
+/// search stops when there are no more open nodes or when a limit is reached, in
+/// which case the certificate stores the greatest upper bound of the nodes left
+/// open or discarded. The node limit only counts the nodes generated in this call.
+/// If there is a checkpoint, the frontier is saved each time its interval of
+/// generated nodes is reached. If there is an incumbent, its solution replaces the
+/// best one when it is better, the improvements are offered to it and the search
+/// stops as if a limit was reached when it is stopped. If the instance has a capacity
+/// every node is a solution, so the nodes that improve the best solution replace it
+/// even if they have fewer points than the specified number
+fn explore(
+    instance: &ProblemInstance,
+    frontier: &mut Frontier,
+    options: &SearchOptions,
+    mut checkpoint: Option<&mut Checkpoint>,
+    incumbent: Option<&Incumbent>,
+    number_of_points: usize,
+    select_partial_solution: fn(&mut Vec<PartialSolution>) -> PartialSolution,
+) -> OptimalityCertificate {
+    let start = Instant::now();
+    let initial_generated_nodes = frontier.generated_nodes;
+    let points = frontier.branching_order.sort_points(instance);
+    let mut lower_bound = frontier.best_solution.get_z();
+    if let Some(incumbent) = incumbent {
+        incumbent.offer_solution(&frontier.best_solution);
+    }
+    prune(&mut frontier.partial_solutions, lower_bound);
+    // The open nodes are only pruned again when the lower bound improves, and the
+    // memory they use is tracked as they are added and removed
+    let mut pruned_lower_bound = lower_bound;
+    let mut memory_usage = get_memory_usage(&frontier.partial_solutions);
+    while !frontier.partial_solutions.is_empty() {
+        if let Some(incumbent) = incumbent {
+            if incumbent.get_z() > lower_bound {
+                if let Some(solution) = incumbent
+                    .get_best_solution()
+                    .filter(|solution| solution.is_feasible(instance, number_of_points))
+                {
+                    lower_bound = solution.get_z();
+                    frontier.best_solution = solution;
+                }
+            }
+        }
+        if options.reached(frontier.generated_nodes - initial_generated_nodes, start)
+            || incumbent.is_some_and(|incumbent| incumbent.is_stopped())
+        {
+            let upper_bound = frontier
+                .partial_solutions
+                .iter()
+                .map(|partial_solution| partial_solution.upper_bound)
+                .fold(frontier.discarded_upper_bound.max(lower_bound), f64::max);
+            return OptimalityCertificate::truncated(lower_bound, upper_bound);
+        }
+        let exceeded = options.exceeded_by(frontier.partial_solutions.len(), memory_usage);
+        let partial_solution = if exceeded {
+            match options.degradation_mode {
+                DegradationMode::DepthFirst => {
+                    get_deepest_partial_solution(&mut frontier.partial_solutions)
+                }
+                DegradationMode::Beam(width) => {
+                    frontier.discarded_upper_bound =
+                        frontier
+                            .discarded_upper_bound
+                            .max(keep_best_partial_solutions(
+                                &mut frontier.partial_solutions,
+                                width,
+                            ));
+                    memory_usage = get_memory_usage(&frontier.partial_solutions);
+                    select_partial_solution(&mut frontier.partial_solutions)
+                }
+            }
+        } else {
+            select_partial_solution(&mut frontier.partial_solutions)
+        };
+        memory_usage -= partial_solution.memory_usage();
+        let possible_indexes =
+            get_possible_points_to_add(&points, &partial_solution, instance, number_of_points);
+        let previous_generated_nodes = frontier.generated_nodes;
+        frontier.generated_nodes += possible_indexes.len();
+        for index in possible_indexes {
+            let partial_solution = get_new_partial_solution(
+                &partial_solution,
+                index,
+                &points,
+                instance,
+                number_of_points,
+            );
+            let size = partial_solution.solution.points.len();
+            if size < number_of_points
+                && instance.get_capacity().is_some()
+                && partial_solution
+                    .solution
+                    .is_feasible(instance, number_of_points)
+            {
+                let z = partial_solution.solution.get_z();
+                if z > lower_bound {
+                    lower_bound = z;
+                    frontier.best_solution = partial_solution.solution.clone();
+                    if let Some(incumbent) = incumbent {
+                        incumbent.offer_solution(&frontier.best_solution);
+                    }
+                }
+            }
+            if size == number_of_points {
+                if partial_solution.upper_bound > lower_bound {
+                    lower_bound = partial_solution.upper_bound;
+                    frontier.best_solution = partial_solution.solution;
+                    if let Some(incumbent) = incumbent {
+                        incumbent.offer_solution(&frontier.best_solution);
+                    }
+                }
+            } else if partial_solution.upper_bound > lower_bound {
+                memory_usage += partial_solution.memory_usage();
+                frontier.partial_solutions.push(partial_solution);
+            }
+        }
+        if lower_bound > pruned_lower_bound {
+            memory_usage -= prune(&mut frontier.partial_solutions, lower_bound);
+            pruned_lower_bound = lower_bound;
+        }
+        if let Some(checkpoint) = checkpoint.as_deref_mut() {
+            if checkpoint.is_due(previous_generated_nodes, frontier.generated_nodes) {
+                checkpoint.save(frontier.to_checkpoint(instance));
+            }
+        }
+    }
+    if frontier.discarded_upper_bound > lower_bound {
+        return OptimalityCertificate::truncated(lower_bound, frontier.discarded_upper_bound);
+    }
+    OptimalityCertificate::optimal(lower_bound)
+}
+
+/// Keeps only the specified number of partial solutions, the ones with the greatest
+/// upper bound. Returns the greatest upper bound of the discarded ones
+fn keep_best_partial_solutions(partial_solutions: &mut Vec<PartialSolution>, width: usize) -> f64 {
+    partial_solutions.sort_by(|a, b| b.upper_bound.partial_cmp(&a.upper_bound).unwrap());
+    partial_solutions
+        .drain(width.min(partial_solutions.len())..)
+        .map(|partial_solution| partial_solution.upper_bound)
+        .fold(f64::NEG_INFINITY, f64::max)
+}
+
 /// Searches in the partial solutions vector and extracts the one with the smallest
 /// upper bound
 fn get_partial_solution_with_smallest_upper_bound(
@@ -74,68 +459,269 @@ fn get_partial_solution_with_smallest_upper_bound(
             if actual.1.upper_bound < min.1.upper_bound {
                 return actual;
             }
-            return min;
+            min
         })
         .map(|(index, _)| index)
         .unwrap();
     partial_solutions.remove(index)
 }
 
-/// Get the points a certain partial solution can add. This is constrained so that
-/// the search tree we get doesn't get repeated paths, which can happen because in
-/// this problem the order in which we add the points doesn't matter. To prevent
-/// this a partial solution can only add n - m + k - i nodes, starting from the
-/// index of last point that was added added. n is the number of total points, m is
-/// the number of points a complete solution has, k is the number of points already
-/// in the partial solution and i is the index of the last added point in the
-/// vector that contains all the points
+/// Get the indexes, in the branching order, of the points a certain partial solution
+/// can add. This is constrained so that the search tree we get doesn't get repeated
+/// paths, which can happen because in this problem the order in which we add the
+/// points doesn't matter. To prevent this a partial solution can only add n - m + k - i
+/// nodes, starting from the index after the last point that was added. n is the
+/// number of points the search can branch on, m is the number of points a complete
+/// solution has, k is the number of points already in the partial solution, including
+/// the fixed ones, and i is the index after the last added point, which the partial
+/// solution stores. If the instance has a capacity the solutions can have fewer
+/// points, so every point after the last added one can be added if it fits. The
+/// points whose group can't take them are left out
 fn get_possible_points_to_add(
-    instance: &ProblemInstance,
+    points: &[Point],
     partial_solution: &PartialSolution,
+    instance: &ProblemInstance,
     m: usize,
-) -> Vec<Point> {
-    let i = if partial_solution.solution.points.len() == 0 {
-        0
-    } else {
-        instance
-            .points
-            .iter()
-            .enumerate()
-            .find(|(_, point)| point == &partial_solution.solution.points.last().unwrap())
-            .map(|(index, _)| index)
-            .unwrap()
-            + 1
-    };
-    let n = instance.points.len();
+) -> Vec<usize> {
+    let n = points.len();
+    let i = partial_solution.next_index;
     let k = partial_solution.solution.points.len();
-    instance
-        .points
-        .iter()
-        .skip(i)
-        .take(n - m + k - i + 1)
-        .cloned()
-        .collect::<Vec<Point>>()
+    let counts = instance.get_group_counts(&partial_solution.solution.points);
+    let can_add =
+        |index: &usize| instance.can_add_to_group(&counts, points[*index].group, m - k - 1);
+    if instance.get_capacity().is_none() {
+        return (i..n - m + k + 1).filter(can_add).collect();
+    }
+    let weight = partial_solution.solution.get_weight();
+    (i..n)
+        .filter(|index| instance.fits(weight + points[*index].weight) && can_add(index))
+        .collect()
 }
 
-/// Get a new partial solution after adding another point
+/// Get a new partial solution after adding the point with the specified index
+/// in the branching order
 fn get_new_partial_solution(
     partial_solution: &PartialSolution,
-    point: Point,
+    index: usize,
+    points: &[Point],
     instance: &ProblemInstance,
     number_of_points: usize,
 ) -> PartialSolution {
-    let mut points = partial_solution.solution.points.clone();
-    points.push(point);
-    let partial_solution =
-        PartialSolution::new(ProblemSolution { points }, instance, number_of_points);
-    partial_solution
+    let mut solution_points = partial_solution.solution.points.clone();
+    solution_points.push(points[index].clone());
+    PartialSolution::new(
+        ProblemSolution {
+            points: solution_points,
+        },
+        index + 1,
+        instance,
+        number_of_points,
+    )
 }
 
 /// Prunes the partial solutions that have an upper bound lower or equal than
-/// the lower bound we already have
-fn prune(partial_solutions: Vec<PartialSolution>, lower_bound: f64) -> Vec<PartialSolution> {
+/// the lower bound we already have. Returns the memory the pruned ones used
+fn prune(partial_solutions: &mut Vec<PartialSolution>, lower_bound: f64) -> usize {
+    let mut freed_memory = 0;
+    partial_solutions.retain(|partial_solution| {
+        if partial_solution.upper_bound > lower_bound {
+            return true;
+        }
+        freed_memory += partial_solution.memory_usage();
+        false
+    });
+    freed_memory
+}
+
+/// Gets the memory in bytes used by the partial solutions
+fn get_memory_usage(partial_solutions: &[PartialSolution]) -> usize {
     partial_solutions
-        .into_iter()
-        .filter(|partial_solution| partial_solution.upper_bound > lower_bound)
-        .collect()
+        .iter()
+        .map(|partial_solution| partial_solution.memory_usage())
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn proven_optimum() {
+        let instance = ProblemInstance::from_file("problem_instances/max_div_15_2.txt").unwrap();
+        let mut solver = BranchAndBound::new(3);
+        let solution = solver.solve(&instance);
+        let certificate = solver.get_certificate().unwrap();
+        assert!(certificate.is_proven_optimal());
+        assert_eq!(certificate.get_lower_bound(), solution.get_z());
+        assert_eq!(certificate.get_gap(), 0.0);
+    }
+    #[test]
+    fn truncated_by_node_limit() {
+        let instance = ProblemInstance::from_file("problem_instances/max_div_30_2.txt").unwrap();
+        let mut solver = DeepBranchAndBound::new(5)
+            .with_initial_solver(GreedySolver::new(5))
+            .with_node_limit(10);
+        let solution = solver.solve(&instance);
+        let certificate = solver.get_certificate().unwrap();
+        assert!(!certificate.is_proven_optimal());
+        assert!(certificate.get_upper_bound() >= solution.get_z());
+        assert!(certificate.get_gap() >= 0.0);
+    }
+    #[test]
+    fn beam_degradation() {
+        let instance = ProblemInstance::from_file("problem_instances/max_div_20_2.txt").unwrap();
+        let mut solver = BranchAndBound::new(4)
+            .with_open_node_limit(5)
+            .with_degradation_mode(DegradationMode::Beam(3));
+        let solution = solver.solve(&instance);
+        let certificate = solver.get_certificate().unwrap();
+        assert_eq!(certificate.get_lower_bound(), solution.get_z());
+        // The discarded nodes could have a better solution, so the optimum isn't proven
+        assert!(!certificate.is_proven_optimal());
+        assert!(certificate.get_upper_bound() > solution.get_z());
+        let mut complete_solver = BranchAndBound::new(4);
+        complete_solver.solve(&instance);
+        assert!(solver.get_generated_nodes() < complete_solver.get_generated_nodes());
+    }
+    #[test]
+    fn memory_limit_degradation() {
+        let instance = ProblemInstance::from_file("problem_instances/max_div_30_2.txt").unwrap();
+        let mut solver = BranchAndBound::new(5)
+            .with_memory_limit(1000)
+            .with_degradation_mode(DegradationMode::Beam(2));
+        let solution = solver.solve(&instance);
+        let certificate = solver.get_certificate().unwrap();
+        assert!(!certificate.is_proven_optimal());
+        assert!(certificate.get_upper_bound() > solution.get_z());
+        let mut complete_solver = BranchAndBound::new(5);
+        complete_solver.solve(&instance);
+        assert!(solver.get_generated_nodes() < complete_solver.get_generated_nodes());
+    }
+    #[test]
+    fn branching_order_keeps_optimum() {
+        let instance = ProblemInstance::from_file("problem_instances/max_div_20_3.txt").unwrap();
+        let optimum = BranchAndBound::new(4).solve(&instance);
+        let solution = BranchAndBound::new(4)
+            .with_branching_order(BranchingOrder::IncreasingTotalDistance)
+            .solve(&instance);
+        assert!((solution.get_z() - optimum.get_z()).abs() < 1e-9);
+    }
+    #[test]
+    fn fixed_and_forbidden_points() {
+        let instance = ProblemInstance::from_file("problem_instances/max_div_20_2.txt")
+            .unwrap()
+            .with_fixed_points(vec![0, 5])
+            .with_forbidden_points(vec![1, 2, 3]);
+        let respects_constraints = |solution: &ProblemSolution| {
+            [0, 5]
+                .iter()
+                .all(|index| solution.points.contains(&instance.points[*index]))
+                && [1, 2, 3]
+                    .iter()
+                    .all(|index| !solution.points.contains(&instance.points[*index]))
+        };
+        let optimum = BranchAndBound::new(4).solve(&instance);
+        assert!(respects_constraints(&optimum));
+        for solution in [
+            DeepBranchAndBound::new(4).solve(&instance),
+            BeamSearch::new(4, 1000).solve(&instance),
+        ] {
+            assert!(respects_constraints(&solution));
+            assert!((solution.get_z() - optimum.get_z()).abs() < 1e-9);
+        }
+        let mut solver = BranchAndBound::new(2);
+        let solution = solver.solve(&instance);
+        assert!(respects_constraints(&solution));
+        assert!(solver.get_certificate().unwrap().is_proven_optimal());
+    }
+    #[test]
+    fn capacity_matches_exhaustive_search() {
+        let instance =
+            ProblemInstance::from_file("problem_instances/max_div_15_2_capacity.txt").unwrap();
+        assert_eq!(instance.points[2].get_weight(), 5.0);
+        let optimal_z = (0..1u32 << instance.points.len())
+            .map(|mask| ProblemSolution {
+                points: (0..instance.points.len())
+                    .filter(|index| mask & (1 << index) != 0)
+                    .map(|index| instance.points[index].clone())
+                    .collect(),
+            })
+            .filter(|solution| solution.get_weight() <= 10.0)
+            .map(|solution| solution.get_z())
+            .fold(0.0, f64::max);
+        // The capacity replaces the number of points, so it doesn't change the optimum
+        for number_of_points in [3, 15] {
+            for solution in [
+                BranchAndBound::new(number_of_points).solve(&instance),
+                DeepBranchAndBound::new(number_of_points).solve(&instance),
+                BeamSearch::new(number_of_points, 10000).solve(&instance),
+            ] {
+                assert!(solution.is_feasible(&instance, number_of_points));
+                assert!((solution.get_z() - optimal_z).abs() < 1e-9);
+            }
+        }
+    }
+    #[test]
+    fn group_limits_match_exhaustive_search() {
+        let instance = ProblemInstance::from_file("problem_instances/max_div_20_2_grouped.txt")
+            .unwrap()
+            .with_group_limits(0, 2, 3)
+            .with_group_limits(1, 0, 1);
+        assert_eq!(instance.points[4].get_group(), 1);
+        let optimal_z = (0..1u32 << instance.points.len())
+            .filter(|mask| mask.count_ones() == 5)
+            .map(|mask| ProblemSolution {
+                points: (0..instance.points.len())
+                    .filter(|index| mask & (1 << index) != 0)
+                    .map(|index| instance.points[index].clone())
+                    .collect(),
+            })
+            .filter(|solution| solution.is_feasible(&instance, 5))
+            .map(|solution| solution.get_z())
+            .fold(0.0, f64::max);
+        for solution in [
+            BranchAndBound::new(5).solve(&instance),
+            DeepBranchAndBound::new(5).solve(&instance),
+            BeamSearch::new(5, 100000).solve(&instance),
+        ] {
+            assert!(solution.is_feasible(&instance, 5));
+            assert!((solution.get_z() - optimal_z).abs() < 1e-9);
+        }
+    }
+    #[test]
+    fn continue_from_frontier() {
+        let instance = ProblemInstance::from_file("problem_instances/max_div_20_2.txt").unwrap();
+        let optimum = BranchAndBound::new(4).solve(&instance);
+        let mut solver = BranchAndBound::new(4).with_node_limit(50);
+        solver.solve(&instance);
+        let mut frontier = solver.take_frontier().unwrap();
+        while !frontier.is_exhausted() {
+            let mut solver = BranchAndBound::new(4)
+                .with_frontier(frontier)
+                .with_node_limit(50);
+            solver.solve(&instance);
+            frontier = solver.take_frontier().unwrap();
+        }
+        assert!((frontier.get_best_solution().get_z() - optimum.get_z()).abs() < 1e-9);
+    }
+    #[test]
+    fn resume_from_checkpoint() {
+        let instance = ProblemInstance::from_file("problem_instances/max_div_20_3.txt").unwrap();
+        let path = std::env::temp_dir().join("daap8_branch_and_bound_checkpoint.txt");
+        let mut complete_solver = DeepBranchAndBound::new(4);
+        let optimum = complete_solver.solve(&instance);
+        let mut solver = DeepBranchAndBound::new(4)
+            .with_checkpoint(&path, 20)
+            .with_node_limit(60);
+        solver.solve(&instance);
+        assert!(solver.get_checkpoint_error().is_none());
+        let frontier = Frontier::load(&path, &instance).unwrap();
+        let mut solver = DeepBranchAndBound::new(4).with_frontier(frontier);
+        let solution = solver.solve(&instance);
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(solution.get_points(), optimum.get_points());
+        assert_eq!(
+            solver.get_generated_nodes(),
+            complete_solver.get_generated_nodes()
+        );
+    }
 }
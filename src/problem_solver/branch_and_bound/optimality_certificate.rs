@@ -0,0 +1,76 @@
+/// The information a branch and bound algorithm can give about the quality of the
+/// solution it returned. If the whole search tree was explored the solution is
+/// a proven optimum. If the search was stopped early because of a limit, the
+/// certificate stores the best upper bound of the nodes that were left open, so
+/// the solution is still useful as long as the gap between both bounds is small
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OptimalityCertificate {
+    lower_bound: f64,
+    upper_bound: f64,
+    proven_optimal: bool,
+}
+
+impl OptimalityCertificate {
+    /// Creates a certificate for a search that explored the whole tree, so the
+    /// lower bound is also the optimum value
+    pub(super) fn optimal(lower_bound: f64) -> Self {
+        OptimalityCertificate {
+            lower_bound,
+            upper_bound: lower_bound,
+            proven_optimal: true,
+        }
+    }
+
+    /// Creates a certificate for a search that was stopped before exploring the whole
+    /// tree. The upper bound can't be smaller than the lower bound
+    pub(super) fn truncated(lower_bound: f64, upper_bound: f64) -> Self {
+        OptimalityCertificate {
+            lower_bound,
+            upper_bound: upper_bound.max(lower_bound),
+            proven_optimal: false,
+        }
+    }
+
+    /// Whether the solution found is a proven optimum
+    pub fn is_proven_optimal(&self) -> bool {
+        self.proven_optimal
+    }
+
+    /// The z of the best solution found
+    pub fn get_lower_bound(&self) -> f64 {
+        self.lower_bound
+    }
+
+    /// The global upper bound. No solution can have a z greater than this value
+    pub fn get_upper_bound(&self) -> f64 {
+        self.upper_bound
+    }
+
+    /// The relative gap between the best solution found and the global upper bound,
+    /// calculated as (upper bound - lower bound) / upper bound. It is 0 if the
+    /// solution is a proven optimum
+    pub fn get_gap(&self) -> f64 {
+        if self.proven_optimal || self.upper_bound <= 0.0 {
+            return 0.0;
+        }
+        (self.upper_bound - self.lower_bound) / self.upper_bound
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn optimal_gap() {
+        let certificate = OptimalityCertificate::optimal(10.0);
+        assert!(certificate.is_proven_optimal());
+        assert_eq!(certificate.get_upper_bound(), 10.0);
+        assert_eq!(certificate.get_gap(), 0.0);
+    }
+    #[test]
+    fn truncated_gap() {
+        let certificate = OptimalityCertificate::truncated(15.0, 20.0);
+        assert!(!certificate.is_proven_optimal());
+        assert_eq!(certificate.get_gap(), 0.25);
+    }
+}
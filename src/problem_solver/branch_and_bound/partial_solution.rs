@@ -1,27 +1,90 @@
 use super::{Point, ProblemInstance, ProblemSolution};
+use std::mem::size_of;
 
 /// A helper struct to represent a partial solution. It stores the incomplete
-/// solution and the upper bound that it has. The upper bound gets calculated
-/// on the constructor
+/// solution, the upper bound that it has and the index of the first point it
+/// can add in the branching order. The upper bound gets calculated on the
+/// constructor
 pub struct PartialSolution {
     pub solution: ProblemSolution,
     pub upper_bound: f64,
+    pub next_index: usize,
 }
 
 impl PartialSolution {
     /// The constructor for a partial solution. Stores the solution and calculates
     /// the upper bound, taking into account how many points are left to add.
-    pub fn new(solution: ProblemSolution, instance: &ProblemInstance, size: usize) -> Self {
-        let points_to_add = size - solution.points.len();
+    pub fn new(
+        solution: ProblemSolution,
+        next_index: usize,
+        instance: &ProblemInstance,
+        size: usize,
+    ) -> Self {
+        let points_to_add = PartialSolution::get_points_to_add(&solution, instance, size);
         let upper_bound = PartialSolution::get_uppper_bound(&solution, instance, points_to_add);
         PartialSolution {
             solution,
             upper_bound,
+            next_index,
+        }
+    }
+
+    /// Creates the root of the search tree, which has the fixed points of the instance
+    pub fn root(instance: &ProblemInstance, size: usize) -> Self {
+        let points = instance
+            .get_fixed_points()
+            .iter()
+            .map(|index| instance.points[*index].clone())
+            .collect();
+        PartialSolution::new(ProblemSolution { points }, 0, instance, size)
+    }
+
+    /// An estimation of the memory in bytes that this partial solution is using
+    pub fn memory_usage(&self) -> usize {
+        size_of::<PartialSolution>()
+            + self
+                .solution
+                .points
+                .iter()
+                .map(|point| size_of::<Point>() + point.get_dimensionality() * size_of::<f64>())
+                .sum::<usize>()
+    }
+
+    /// Gets how many points can still be added to the solution. If the instance has a
+    /// capacity, it is limited by how many of the lightest points that can be added fit
+    fn get_points_to_add(
+        solution: &ProblemSolution,
+        instance: &ProblemInstance,
+        size: usize,
+    ) -> usize {
+        let points_to_add = size - solution.points.len();
+        if instance.get_capacity().is_none() {
+            return points_to_add;
         }
+        let mut weights = instance
+            .points
+            .iter()
+            .enumerate()
+            .filter(|(index, point)| {
+                !instance.is_forbidden_index(*index) && !solution.points.contains(point)
+            })
+            .map(|(_, point)| point.weight)
+            .collect::<Vec<f64>>();
+        weights.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        let mut weight = solution.get_weight();
+        weights
+            .into_iter()
+            .take(points_to_add)
+            .take_while(|point_weight| {
+                weight += point_weight;
+                instance.fits(weight)
+            })
+            .count()
     }
 
     /// Calculates the upper bound. It calculates the upper bounds of adding each point
-    /// and then selects the ones that give the greater upper bound
+    /// that isn't forbidden and fits and then selects the ones that give the greater
+    /// upper bound
     fn get_uppper_bound(
         solution: &ProblemSolution,
         instance: &ProblemInstance,
@@ -31,17 +94,18 @@ impl PartialSolution {
         if points_to_add == 0 {
             return upper_bound;
         }
+        let weight = solution.get_weight();
         let mut point_upper_bounds = instance
             .points
             .iter()
-            .filter(|point| !solution.points.contains(point))
-            .map(|point| {
-                PartialSolution::get_point_upper_bound(
-                    &solution,
-                    instance,
-                    point,
-                    points_to_add - 1,
-                )
+            .enumerate()
+            .filter(|(index, point)| {
+                !instance.is_forbidden_index(*index)
+                    && instance.fits(weight + point.weight)
+                    && !solution.points.contains(point)
+            })
+            .map(|(_, point)| {
+                PartialSolution::get_point_upper_bound(solution, instance, point, points_to_add - 1)
             })
             .collect::<Vec<f64>>();
         for _ in 0..points_to_add {
@@ -52,7 +116,7 @@ impl PartialSolution {
                     if actual.1 > max.1 {
                         return actual;
                     }
-                    return max;
+                    max
                 })
                 .map(|(index, _)| index)
                 .unwrap();
@@ -82,7 +146,7 @@ impl PartialSolution {
                     if actual.1 > max.1 {
                         return actual;
                     }
-                    return max;
+                    max
                 })
                 .map(|(index, _)| index)
                 .unwrap();
@@ -102,7 +166,12 @@ fn get_distances_to_points_outside_solution(
     instance
         .points
         .iter()
-        .filter(|other_point| !solution.points.contains(point) && point != *other_point)
-        .map(|other_point| other_point.distance_to(point))
+        .enumerate()
+        .filter(|(index, other_point)| {
+            !instance.is_forbidden_index(*index)
+                && !solution.points.contains(point)
+                && point != *other_point
+        })
+        .map(|(_, other_point)| other_point.distance_to(point))
         .collect()
 }
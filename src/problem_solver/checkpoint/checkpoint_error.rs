@@ -0,0 +1,33 @@
+//! This file defines the different errors that can appear while
+//! saving or loading the checkpoint of a search
+use std::error::Error;
+use std::fmt;
+
+/// The enum that encapsulates the two types of error that can appear while
+/// saving or loading a checkpoint. It can be an IO error or a SyntaxError
+#[derive(Debug)]
+pub enum CheckpointError {
+    IOError(std::io::Error),
+    SyntaxError(usize),
+}
+
+impl fmt::Display for CheckpointError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CheckpointError::IOError(error) => {
+                write!(f, "There has been an IO error: {}", error)
+            }
+            CheckpointError::SyntaxError(line) => {
+                write!(f, "A syntax error has been found at line {}", line)
+            }
+        }
+    }
+}
+
+impl Error for CheckpointError {}
+
+impl From<std::io::Error> for CheckpointError {
+    fn from(error: std::io::Error) -> Self {
+        CheckpointError::IOError(error)
+    }
+}
@@ -0,0 +1,174 @@
+//! This module defines the helpers used to save the state of a long search to
+//! a file and load it later. The files have a line for each value and use a tab
+//! as separator when a line has several values. The points are saved as their
+//! index in the instance, so the same instance is needed to load a checkpoint
+use super::{Point, ProblemInstance, ProblemSolution};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+mod checkpoint_error;
+pub use checkpoint_error::CheckpointError;
+
+const SEPARATOR: &str = "\t";
+
+/// The configuration of the periodic checkpoints of a search. It also stores
+/// the last error that happened while saving, so a failed checkpoint doesn't
+/// stop the search
+pub(crate) struct Checkpoint {
+    path: PathBuf,
+    interval: usize,
+    error: Option<CheckpointError>,
+}
+
+impl Checkpoint {
+    /// Creates a checkpoint that will be saved to the specified path each time
+    /// the progress of the search advances the specified interval
+    pub(crate) fn new<P: AsRef<Path>>(path: P, interval: usize) -> Self {
+        assert!(interval > 0);
+        Checkpoint {
+            path: path.as_ref().to_path_buf(),
+            interval,
+            error: None,
+        }
+    }
+
+    /// Whether a checkpoint should be saved after the progress advanced from
+    /// the previous value to the actual one
+    pub(crate) fn is_due(&self, previous_progress: usize, progress: usize) -> bool {
+        previous_progress / self.interval != progress / self.interval
+    }
+
+    /// Saves the contents of the checkpoint. If it fails, the error is stored
+    pub(crate) fn save(&mut self, contents: String) {
+        if let Err(error) = write_checkpoint(&self.path, contents) {
+            self.error = Some(error);
+        }
+    }
+
+    /// The last error that happened while saving a checkpoint
+    pub(crate) fn get_error(&self) -> Option<&CheckpointError> {
+        self.error.as_ref()
+    }
+}
+
+/// Writes a checkpoint file. It is written under another name and then renamed,
+/// so a search killed while saving doesn't corrupt the previous checkpoint
+pub(crate) fn write_checkpoint<P: AsRef<Path>>(
+    path: P,
+    contents: String,
+) -> Result<(), CheckpointError> {
+    let mut temporary_path = path.as_ref().as_os_str().to_os_string();
+    temporary_path.push(".tmp");
+    fs::write(&temporary_path, contents)?;
+    fs::rename(&temporary_path, path)?;
+    Ok(())
+}
+
+/// Gets the line that represents a solution, with the index of each point
+pub(crate) fn solution_to_line(solution: &ProblemSolution, instance: &ProblemInstance) -> String {
+    points_to_line(&solution.points, instance)
+}
+
+/// Gets the line that represents a list of points, with the index of each one
+pub(crate) fn points_to_line(points: &[Point], instance: &ProblemInstance) -> String {
+    let indexes = points
+        .iter()
+        .map(|point| {
+            instance
+                .points
+                .iter()
+                .position(|instance_point| instance_point == point)
+                // The points of a search always come from the instance
+                .unwrap()
+        })
+        .collect::<Vec<usize>>();
+    values_to_line(&indexes)
+}
+
+/// Gets the line that represents a list of values
+pub(crate) fn values_to_line<T: ToString>(values: &[T]) -> String {
+    values
+        .iter()
+        .map(|value| value.to_string())
+        .collect::<Vec<String>>()
+        .join(SEPARATOR)
+}
+
+/// A helper to read the lines of a checkpoint file one by one, keeping track of
+/// the line number to report syntax errors
+pub(crate) struct CheckpointReader<'a> {
+    lines: Vec<String>,
+    line: usize,
+    instance: &'a ProblemInstance,
+}
+
+impl<'a> CheckpointReader<'a> {
+    /// Reads the whole file
+    pub(crate) fn from_file<P: AsRef<Path>>(
+        path: P,
+        instance: &'a ProblemInstance,
+    ) -> Result<Self, CheckpointError> {
+        let lines = fs::read_to_string(path)?
+            .lines()
+            .map(|line| line.to_string())
+            .collect();
+        Ok(CheckpointReader {
+            lines,
+            line: 0,
+            instance,
+        })
+    }
+
+    fn next_line(&mut self) -> Result<&str, CheckpointError> {
+        self.line += 1;
+        self.lines
+            .get(self.line - 1)
+            .map(|line| line.trim())
+            .ok_or(CheckpointError::SyntaxError(self.line))
+    }
+
+    /// The number of the last line that has been read
+    pub(crate) fn get_line(&self) -> usize {
+        self.line
+    }
+
+    /// Reads a line with a single value
+    pub(crate) fn read_value<T: FromStr>(&mut self) -> Result<T, CheckpointError> {
+        let line = self.line + 1;
+        self.next_line()?
+            .parse::<T>()
+            .map_err(|_| CheckpointError::SyntaxError(line))
+    }
+
+    /// Reads a line with several values
+    pub(crate) fn read_values<T: FromStr>(&mut self) -> Result<Vec<T>, CheckpointError> {
+        let line = self.line + 1;
+        let string = self.next_line()?;
+        if string.is_empty() {
+            return Ok(Vec::new());
+        }
+        string
+            .split(SEPARATOR)
+            .map(|value| value.parse::<T>())
+            .collect::<Result<Vec<T>, T::Err>>()
+            .map_err(|_| CheckpointError::SyntaxError(line))
+    }
+
+    /// Reads a line with the indexes of a list of points
+    pub(crate) fn read_points(&mut self) -> Result<Vec<Point>, CheckpointError> {
+        let line = self.line + 1;
+        self.read_values::<usize>()?
+            .into_iter()
+            .map(|index| self.instance.points.get(index).cloned())
+            .collect::<Option<Vec<Point>>>()
+            .ok_or(CheckpointError::SyntaxError(line))
+    }
+
+    /// Reads a line with the indexes of the points of a solution
+    pub(crate) fn read_solution(&mut self) -> Result<ProblemSolution, CheckpointError> {
+        Ok(ProblemSolution {
+            points: self.read_points()?,
+        })
+    }
+}
@@ -0,0 +1,229 @@
+use super::{
+    GreedyCriterion, ProblemInstance, ProblemSolution, ProblemSolver, ScoringFunction,
+    SelectionRule,
+};
+use rand::{rngs::StdRng, SeedableRng};
+
+/// A constructive algorithm that creates a solution by adding a point to it in
+/// each step, or dropping one if the [scoring function](super::ScoringFunction) is
+/// destructive. The candidates are scored with the scoring function and one of them
+/// is chosen with the [selection rule](super::SelectionRule). The
+/// [greedy solver](super::GreedySolver) and the
+/// [randomized greedy solver](super::RandomizedGreedySolver) are configurations of it.
+/// The solutions always include the fixed points of the instance and never the
+/// forbidden ones. If the instance has a capacity, the points that don't fit are not
+/// candidates and the solution is complete when no point fits, or the destructive
+/// criteria drop points until the rest fit. The points whose group would exceed its
+/// maximum or leave no room for the minimums of the other groups aren't candidates
+/// either
+pub struct ConstructiveSolver<S: ScoringFunction = GreedyCriterion> {
+    number_of_points: usize,
+    scoring_function: S,
+    selection_rule: SelectionRule,
+    rng: StdRng,
+}
+
+impl<S: ScoringFunction> ProblemSolver for ConstructiveSolver<S> {
+    fn solve(&mut self, instance: &ProblemInstance) -> ProblemSolution {
+        assert!(instance.is_feasible(self.number_of_points));
+        let number_of_points = instance.get_max_points(self.number_of_points);
+        let mut points = instance
+            .points
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| !instance.is_forbidden_index(*index))
+            .map(|(_, point)| point.clone())
+            .collect::<Vec<_>>();
+        if self.scoring_function.is_destructive() {
+            loop {
+                let counts = instance.get_group_counts(&points);
+                let exceeded_groups = counts
+                    .iter()
+                    .filter(|(group, count)| **count > instance.get_group_limits(**group).1)
+                    .map(|(group, _)| *group)
+                    .collect::<Vec<usize>>();
+                if points.len() <= number_of_points
+                    && instance.fits(points.iter().map(|point| point.weight).sum())
+                    && exceeded_groups.is_empty()
+                {
+                    break;
+                }
+                let candidates = points
+                    .iter()
+                    .filter(|point| {
+                        !instance.is_fixed(point)
+                            && if exceeded_groups.is_empty() {
+                                instance.can_exchange_groups(&counts, &[point.group], &[])
+                            } else {
+                                exceeded_groups.contains(&point.group)
+                            }
+                    })
+                    .cloned()
+                    .collect::<Vec<_>>();
+                if candidates.is_empty() {
+                    break;
+                }
+                let scores = self
+                    .scoring_function
+                    .get_scores(instance, &candidates, &points);
+                let dropped_point = &candidates[self.selection_rule.select(&scores, &mut self.rng)];
+                points.retain(|point| point != dropped_point);
+            }
+            return ProblemSolution { points };
+        }
+        let (mut solution_points, mut points): (Vec<_>, Vec<_>) = points
+            .into_iter()
+            .partition(|point| instance.is_fixed(point));
+        let mut weight = solution_points
+            .iter()
+            .map(|point| point.weight)
+            .sum::<f64>();
+        while solution_points.len() < number_of_points {
+            points.retain(|point| instance.fits(weight + point.weight));
+            let counts = instance.get_group_counts(&solution_points);
+            let points_left = number_of_points - solution_points.len() - 1;
+            let candidate_indexes = (0..points.len())
+                .filter(|index| {
+                    instance.can_add_to_group(&counts, points[*index].group, points_left)
+                })
+                .collect::<Vec<usize>>();
+            if candidate_indexes.is_empty() {
+                break;
+            }
+            let candidates = candidate_indexes
+                .iter()
+                .map(|index| points[*index].clone())
+                .collect::<Vec<_>>();
+            let scores = self
+                .scoring_function
+                .get_scores(instance, &candidates, &solution_points);
+            let selected_index = self.selection_rule.select(&scores, &mut self.rng);
+            let new_point = points.remove(candidate_indexes[selected_index]);
+            weight += new_point.weight;
+            solution_points.push(new_point);
+        }
+        ProblemSolution {
+            points: solution_points,
+        }
+    }
+}
+
+impl<S: ScoringFunction> ConstructiveSolver<S> {
+    /// Creates a new solver. The number of points the solution will have and the
+    /// scoring function need to be passed as arguments. By default the candidate
+    /// with the best score is chosen
+    pub fn new(number_of_points: usize, scoring_function: S) -> Self {
+        assert!(number_of_points > 0);
+        ConstructiveSolver {
+            number_of_points,
+            scoring_function,
+            selection_rule: SelectionRule::default(),
+            rng: StdRng::from_entropy(),
+        }
+    }
+
+    /// Sets the rule used to choose a candidate
+    pub fn with_selection_rule(mut self, selection_rule: SelectionRule) -> Self {
+        if let SelectionRule::RestrictedCandidateList(restricted_candidate_list, selection_bias) =
+            selection_rule
+        {
+            assert!(restricted_candidate_list.is_valid() && selection_bias.is_valid());
+        }
+        self.selection_rule = selection_rule;
+        self
+    }
+
+    /// Seeds the random number generator, so the solutions are reproducible
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.rng = StdRng::seed_from_u64(seed);
+        self
+    }
+
+    /// Replaces the scoring function, keeping the rest of the configuration
+    pub(super) fn with_scoring_function<T: ScoringFunction>(
+        self,
+        scoring_function: T,
+    ) -> ConstructiveSolver<T> {
+        ConstructiveSolver {
+            number_of_points: self.number_of_points,
+            scoring_function,
+            selection_rule: self.selection_rule,
+            rng: self.rng,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Point;
+    struct FirstCoordinate;
+    impl ScoringFunction for FirstCoordinate {
+        fn get_scores(&self, _: &ProblemInstance, candidates: &[Point], _: &[Point]) -> Vec<f64> {
+            candidates
+                .iter()
+                .map(|point| point.coordinates[0])
+                .collect()
+        }
+    }
+    #[test]
+    fn custom_scoring_function() {
+        let instance = ProblemInstance::from_file("problem_instances/max_div_15_2.txt").unwrap();
+        let solution = ConstructiveSolver::new(3, FirstCoordinate).solve(&instance);
+        let mut first_coordinates = instance
+            .points
+            .iter()
+            .map(|point| point.coordinates[0])
+            .collect::<Vec<f64>>();
+        first_coordinates.sort_by(|a, b| b.total_cmp(a));
+        let mut solution_coordinates = solution
+            .get_points()
+            .iter()
+            .map(|point| point.coordinates[0])
+            .collect::<Vec<f64>>();
+        solution_coordinates.sort_by(|a, b| b.total_cmp(a));
+        assert_eq!(solution_coordinates, first_coordinates[..3].to_vec());
+    }
+    #[test]
+    fn fixed_and_forbidden_points() {
+        let instance = ProblemInstance::from_file("problem_instances/max_div_15_2.txt")
+            .unwrap()
+            .with_fixed_points(vec![3])
+            .with_forbidden_points(vec![0, 1]);
+        for criterion in [GreedyCriterion::Centroid, GreedyCriterion::Destructive] {
+            let solution = ConstructiveSolver::new(4, criterion).solve(&instance);
+            assert_eq!(solution.points.len(), 4);
+            assert!(solution.points.contains(&instance.points[3]));
+            assert!(!solution.points.contains(&instance.points[0]));
+            assert!(!solution.points.contains(&instance.points[1]));
+        }
+    }
+    #[test]
+    fn capacity() {
+        let instance = ProblemInstance::from_file("problem_instances/max_div_15_2_weighted.txt")
+            .unwrap()
+            .with_capacity(10.0);
+        for criterion in [GreedyCriterion::MaxSum, GreedyCriterion::Destructive] {
+            let solution = ConstructiveSolver::new(15, criterion).solve(&instance);
+            assert!(solution.is_feasible(&instance, 15));
+            if criterion == GreedyCriterion::MaxSum {
+                assert!(instance
+                    .points
+                    .iter()
+                    .filter(|point| !solution.points.contains(point))
+                    .all(|point| solution.get_weight() + point.get_weight() > 10.0));
+            }
+        }
+    }
+    #[test]
+    fn group_limits() {
+        let instance = ProblemInstance::from_file("problem_instances/max_div_20_2_grouped.txt")
+            .unwrap()
+            .with_group_limits(0, 3, 4)
+            .with_group_limits(2, 0, 0);
+        for criterion in [GreedyCriterion::MaxMin, GreedyCriterion::Destructive] {
+            let solution = ConstructiveSolver::new(5, criterion).solve(&instance);
+            assert!(solution.is_feasible(&instance, 5));
+        }
+    }
+}
@@ -0,0 +1,45 @@
+/// How the temperature of a [simulated annealing](super::SimulatedAnnealing)
+/// decreases after each temperature level
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CoolingSchedule {
+    /// The temperature is multiplied by the specified factor, which must be between 0 and 1
+    Geometric(f64),
+    /// The temperature decreases by the same amount after each level, so it
+    /// reaches 0 at the last one
+    Linear,
+    /// The temperature decreases depending on how much the z of the solutions
+    /// visited in the last level varied, so it cools slowly while the search is
+    /// still moving between very different solutions. The value controls the speed
+    /// of the cooling, the greater the faster, and it must be positive
+    Adaptive(f64),
+}
+
+impl Default for CoolingSchedule {
+    fn default() -> Self {
+        CoolingSchedule::Geometric(0.95)
+    }
+}
+
+impl CoolingSchedule {
+    /// Gets the temperature of the next level. It needs the temperature the search
+    /// started with, the total number of levels and the standard deviation of the z
+    /// of the solutions visited in the last level
+    pub(super) fn cool(
+        &self,
+        temperature: f64,
+        initial_temperature: f64,
+        levels: usize,
+        standard_deviation: f64,
+    ) -> f64 {
+        match self {
+            CoolingSchedule::Geometric(factor) => temperature * factor,
+            CoolingSchedule::Linear => (temperature - initial_temperature / levels as f64).max(0.0),
+            CoolingSchedule::Adaptive(speed) => {
+                if standard_deviation <= 0.0 {
+                    return temperature;
+                }
+                temperature / (1.0 + temperature * (1.0 + speed).ln() / (3.0 * standard_deviation))
+            }
+        }
+    }
+}
@@ -0,0 +1,101 @@
+use super::ProblemInstance;
+use rand::{seq::SliceRandom, Rng};
+
+/// The crossover operator a [genetic algorithm](super::GeneticAlgorithm) uses to
+/// create a child from two parents. The solutions are represented by the indexes
+/// of their points in the instance, and both operators keep the points the parents
+/// have in common and create a child with the same number of points. The child only
+/// has points of the parents, so if they have the fixed points and none of the
+/// forbidden ones so does the child
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Crossover {
+    /// Each point that is only in one of the parents is inherited with probability
+    /// 0.5. Then the child is repaired by removing random points if it has too
+    /// many or adding random points of the parents if it has too few
+    #[default]
+    Uniform,
+    /// The points that are only in one of the parents are added greedily, choosing
+    /// each time the one with the greatest sum of distances to the points already
+    /// in the child
+    Greedy,
+}
+
+impl Crossover {
+    /// Creates a child with the same number of points as the parents
+    pub(super) fn cross<R: Rng>(
+        &self,
+        instance: &ProblemInstance,
+        parent1: &[usize],
+        parent2: &[usize],
+        rng: &mut R,
+    ) -> Vec<usize> {
+        let mut child = parent1
+            .iter()
+            .filter(|index| parent2.contains(index))
+            .cloned()
+            .collect::<Vec<usize>>();
+        let mut candidates = parent1
+            .iter()
+            .chain(parent2.iter())
+            .filter(|index| !child.contains(index))
+            .cloned()
+            .collect::<Vec<usize>>();
+        match self {
+            Crossover::Uniform => {
+                let missing_points = parent1.len() - child.len();
+                candidates.shuffle(rng);
+                let (mut inherited, rest): (Vec<usize>, Vec<usize>) =
+                    candidates.into_iter().partition(|_| rng.gen_bool(0.5));
+                inherited.truncate(missing_points);
+                let repaired_points = missing_points - inherited.len();
+                child.extend(inherited);
+                child.extend(rest.into_iter().take(repaired_points));
+            }
+            Crossover::Greedy => {
+                while child.len() < parent1.len() {
+                    let position = (0..candidates.len())
+                        .map(|position| {
+                            let contribution = child
+                                .iter()
+                                .map(|index| {
+                                    instance.points[*index]
+                                        .distance_to(&instance.points[candidates[position]])
+                                })
+                                .sum::<f64>();
+                            (position, contribution)
+                        })
+                        .reduce(|acc, value| {
+                            if value.1 > acc.1 {
+                                return value;
+                            }
+                            acc
+                        })
+                        // The parents have the same number of points, so there are
+                        // always enough candidates
+                        .unwrap()
+                        .0;
+                    child.push(candidates.swap_remove(position));
+                }
+            }
+        }
+        child.sort_unstable();
+        child
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{rngs::StdRng, SeedableRng};
+    #[test]
+    fn child_keeps_common_points_and_size() {
+        let instance = ProblemInstance::from_file("problem_instances/max_div_15_2.txt").unwrap();
+        let mut rng = StdRng::seed_from_u64(0);
+        for crossover in [Crossover::Uniform, Crossover::Greedy] {
+            let child = crossover.cross(&instance, &[0, 2, 4, 6], &[1, 2, 3, 6], &mut rng);
+            assert_eq!(child.len(), 4);
+            assert!(child.contains(&2) && child.contains(&6));
+            assert!(child.iter().all(|index| [0, 1, 3, 4, 2, 6].contains(index)));
+        }
+    }
+}
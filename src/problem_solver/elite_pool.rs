@@ -0,0 +1,154 @@
+use super::ProblemSolution;
+
+/// A set of the best solutions found by a search that are different enough from
+/// each other. A solution enters the pool if it is better than the best solution
+/// of the pool, or if it is better than the worst one and it has at least the
+/// minimum distance to every solution of the pool. The distance between two
+/// solutions is the number of points of one of them that aren't in the other. When
+/// the pool is full the new solution replaces the most similar of the solutions
+/// that are worse than it
+pub struct ElitePool {
+    solutions: Vec<ProblemSolution>,
+    capacity: usize,
+    min_distance: usize,
+}
+
+impl ElitePool {
+    /// Creates an empty pool that can hold the specified number of solutions. By
+    /// default the solutions only need to be different
+    pub fn new(capacity: usize) -> Self {
+        assert!(capacity > 0);
+        ElitePool {
+            solutions: Vec::with_capacity(capacity),
+            capacity,
+            min_distance: 1,
+        }
+    }
+
+    /// Sets the minimum distance a solution needs to have to the solutions of the
+    /// pool to enter it, unless it is better than all of them
+    pub fn with_min_distance(mut self, min_distance: usize) -> Self {
+        assert!(min_distance > 0);
+        self.min_distance = min_distance;
+        self
+    }
+
+    /// Gets the distance between two solutions, which is the number of points of
+    /// the first one that aren't in the second one
+    pub fn get_distance(solution1: &ProblemSolution, solution2: &ProblemSolution) -> usize {
+        solution1
+            .points
+            .iter()
+            .filter(|point| !solution2.points.contains(point))
+            .count()
+    }
+
+    /// Tries to add a solution to the pool and returns whether it was added
+    pub fn offer_solution(&mut self, solution: ProblemSolution) -> bool {
+        let z = solution.get_z();
+        if self
+            .solutions
+            .iter()
+            .any(|other_solution| ElitePool::get_distance(&solution, other_solution) == 0)
+        {
+            return false;
+        }
+        let is_best = self.get_best_solution().is_none_or(|best| z > best.get_z());
+        let is_diverse = self.solutions.iter().all(|other_solution| {
+            ElitePool::get_distance(&solution, other_solution) >= self.min_distance
+        });
+        if !is_best && !is_diverse {
+            return false;
+        }
+        if self.solutions.len() < self.capacity {
+            self.solutions.push(solution);
+            return true;
+        }
+        let most_similar_worse_solution = self
+            .solutions
+            .iter()
+            .enumerate()
+            .filter(|(_, other_solution)| other_solution.get_z() < z)
+            .map(|(index, other_solution)| {
+                (index, ElitePool::get_distance(&solution, other_solution))
+            })
+            .reduce(|acc, value| {
+                if value.1 < acc.1 {
+                    return value;
+                }
+                acc
+            });
+        match most_similar_worse_solution {
+            Some((index, _)) => {
+                self.solutions[index] = solution;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// The solutions of the pool
+    pub fn get_solutions(&self) -> &[ProblemSolution] {
+        &self.solutions
+    }
+
+    /// The best solution of the pool, if it isn't empty
+    pub fn get_best_solution(&self) -> Option<&ProblemSolution> {
+        self.solutions.iter().reduce(|acc, solution| {
+            if solution.get_z() > acc.get_z() {
+                return solution;
+            }
+            acc
+        })
+    }
+
+    /// The solution of the pool with the greatest distance to the given one, if the
+    /// pool isn't empty
+    pub fn get_most_different_solution(
+        &self,
+        solution: &ProblemSolution,
+    ) -> Option<&ProblemSolution> {
+        self.solutions
+            .iter()
+            .map(|other_solution| {
+                (
+                    other_solution,
+                    ElitePool::get_distance(solution, other_solution),
+                )
+            })
+            .reduce(|acc, value| {
+                if value.1 > acc.1 {
+                    return value;
+                }
+                acc
+            })
+            .map(|(other_solution, _)| other_solution)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ProblemInstance;
+    #[test]
+    fn keeps_best_diverse_solutions() {
+        let instance = ProblemInstance::from_file("problem_instances/max_div_20_2.txt").unwrap();
+        let mut pool = ElitePool::new(2).with_min_distance(2);
+        let solutions = (0..5)
+            .map(|start| ProblemSolution {
+                points: instance.points[start..start + 4].to_vec(),
+            })
+            .collect::<Vec<ProblemSolution>>();
+        assert!(pool.offer_solution(solutions[0].clone()));
+        assert!(!pool.offer_solution(solutions[0].clone()));
+        for solution in solutions.iter().skip(1) {
+            pool.offer_solution(solution.clone());
+        }
+        let best_z = solutions
+            .iter()
+            .map(|solution| solution.get_z())
+            .fold(f64::NEG_INFINITY, f64::max);
+        assert_eq!(pool.get_solutions().len(), 2);
+        assert_eq!(pool.get_best_solution().unwrap().get_z(), best_z);
+    }
+}
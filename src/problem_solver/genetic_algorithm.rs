@@ -0,0 +1,366 @@
+use super::local_search::{add_best_fitting_point, is_possible_swap, LocalSearch};
+use super::{
+    Crossover, GreedySolver, Incumbent, ProblemInstance, ProblemSolution, ProblemSolver,
+    RandomizedGreedySolver,
+};
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+
+/// A implementation of a steady state genetic algorithm. Each individual is
+/// represented by the sorted indexes of its points in the instance, and the initial
+/// population is created with the [randomized greedy solver](super::RandomizedGreedySolver).
+/// In each step two parents are chosen by binary tournament, they are combined with
+/// the [crossover operator](super::Crossover) and the child can be mutated by a random
+/// swap. If a local search is given the child is also improved with it, turning the
+/// algorithm into a memetic one. A child enters the population only if it isn't
+/// already in it and it is better than some individual, replacing the most similar
+/// of those worse than it, so the population keeps its diversity. A child that
+/// doesn't respect the capacity or the group limits is repaired with points of its
+/// first parent, the mutation only performs swaps that respect them and, if the
+/// instance has a capacity, the child is completed with the points that fit
+pub struct GeneticAlgorithm {
+    number_of_points: usize,
+    population_size: usize,
+    generations: usize,
+    rcl_size: usize,
+    crossover: Crossover,
+    mutation_probability: f64,
+    local_search: Option<Box<dyn LocalSearch + Send>>,
+    rng: StdRng,
+    incumbent: Option<Incumbent>,
+}
+
+/// A solution of the population with its z
+struct Individual {
+    indexes: Vec<usize>,
+    z: f64,
+}
+
+impl ProblemSolver for GeneticAlgorithm {
+    fn solve(&mut self, instance: &ProblemInstance) -> ProblemSolution {
+        // If every point that isn't forbidden is in the solution there is only one
+        // individual
+        if instance.get_capacity().is_none()
+            && self.number_of_points + instance.get_forbidden_points().len()
+                >= instance.points.len()
+        {
+            return GreedySolver::new(self.number_of_points).solve(instance);
+        }
+        let mut constructive_solver =
+            RandomizedGreedySolver::new(self.number_of_points, self.rcl_size)
+                .with_seed(self.rng.gen());
+        let mut population: Vec<Individual> = Vec::with_capacity(self.population_size);
+        for individual in 0..self.population_size {
+            if individual > 0 && self.is_stopped() {
+                break;
+            }
+            let solution = constructive_solver.solve(instance);
+            let individual = self.create_individual(instance, solution);
+            self.replace(&mut population, individual);
+        }
+        for _ in 0..self.generations * self.population_size {
+            if self.is_stopped() {
+                break;
+            }
+            let parent1 = self.select_parent(&population);
+            let parent2 = self.select_parent(&population);
+            let mut indexes = self.crossover.cross(
+                instance,
+                &population[parent1].indexes,
+                &population[parent2].indexes,
+                &mut self.rng,
+            );
+            self.repair(instance, &population[parent1].indexes, &mut indexes);
+            if self.rng.gen_bool(self.mutation_probability) {
+                self.mutate(instance, &mut indexes);
+            }
+            let individual =
+                self.create_individual(instance, GeneticAlgorithm::to_solution(instance, &indexes));
+            self.replace(&mut population, individual);
+        }
+        let best_individual = population
+            .into_iter()
+            .reduce(|acc, individual| {
+                if individual.z > acc.z {
+                    return individual;
+                }
+                acc
+            })
+            // The population can't be empty
+            .unwrap();
+        GeneticAlgorithm::to_solution(instance, &best_individual.indexes)
+    }
+}
+
+impl GeneticAlgorithm {
+    /// Creates a new genetic algorithm with the specified arguments. The number of
+    /// points the solution will have, the size of the population and the number of
+    /// generations. Each generation creates as many children as the population size
+    pub fn new(number_of_points: usize, population_size: usize, generations: usize) -> Self {
+        assert!(number_of_points > 0 && population_size > 1);
+        GeneticAlgorithm {
+            number_of_points,
+            population_size,
+            generations,
+            rcl_size: 3,
+            crossover: Crossover::default(),
+            mutation_probability: 0.1,
+            local_search: None,
+            rng: StdRng::from_entropy(),
+            incumbent: None,
+        }
+    }
+
+    /// Sets the size of the restricted candidate list of the solver that creates the
+    /// initial population. By default it is 3
+    pub fn with_rcl_size(mut self, rcl_size: usize) -> Self {
+        assert!(rcl_size > 0);
+        self.rcl_size = rcl_size;
+        self
+    }
+
+    /// Sets the crossover operator. By default it is the uniform crossover
+    pub fn with_crossover(mut self, crossover: Crossover) -> Self {
+        self.crossover = crossover;
+        self
+    }
+
+    /// Sets the probability of a child being mutated. By default it is 0.1
+    pub fn with_mutation_probability(mut self, mutation_probability: f64) -> Self {
+        assert!((0.0..=1.0).contains(&mutation_probability));
+        self.mutation_probability = mutation_probability;
+        self
+    }
+
+    /// Improves each individual with the specified local search before it enters the
+    /// population
+    pub fn with_local_search<L: LocalSearch + Send + 'static>(mut self, local_search: L) -> Self {
+        self.local_search = Some(Box::new(local_search));
+        self
+    }
+
+    /// Seeds the random number generator, so the solutions are reproducible
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.rng = StdRng::seed_from_u64(seed);
+        self
+    }
+
+    /// Offers every individual created to an [incumbent](super::Incumbent) shared
+    /// with other solvers, and stops as soon as the incumbent is stopped, returning
+    /// the best individual of the population
+    pub fn with_incumbent(mut self, incumbent: Incumbent) -> Self {
+        self.incumbent = Some(incumbent);
+        self
+    }
+
+    /// Whether the incumbent shared with other solvers has been stopped
+    fn is_stopped(&self) -> bool {
+        self.incumbent
+            .as_ref()
+            .is_some_and(|incumbent| incumbent.is_stopped())
+    }
+
+    /// Creates an individual from a solution, improving it first with the local
+    /// search if there is one. The solution is offered to the incumbent
+    fn create_individual(
+        &self,
+        instance: &ProblemInstance,
+        solution: ProblemSolution,
+    ) -> Individual {
+        let mut solution = match &self.local_search {
+            Some(local_search) => local_search.improve(instance, solution),
+            None => solution,
+        };
+        while add_best_fitting_point(instance, &mut solution) {}
+        let mut indexes = solution
+            .points
+            .iter()
+            .map(|point| {
+                // The points of the solution come from the instance
+                instance
+                    .points
+                    .iter()
+                    .position(|other_point| other_point == point)
+                    .unwrap()
+            })
+            .collect::<Vec<usize>>();
+        indexes.sort_unstable();
+        if let Some(incumbent) = &self.incumbent {
+            incumbent.offer_solution(&solution);
+        }
+        Individual {
+            indexes,
+            z: solution.get_z(),
+        }
+    }
+
+    fn to_solution(instance: &ProblemInstance, indexes: &[usize]) -> ProblemSolution {
+        ProblemSolution {
+            points: indexes
+                .iter()
+                .map(|index| instance.points[*index].clone())
+                .collect(),
+        }
+    }
+
+    /// Chooses the index of a parent by binary tournament
+    fn select_parent(&mut self, population: &[Individual]) -> usize {
+        let candidate1 = self.rng.gen_range(0..population.len());
+        let candidate2 = self.rng.gen_range(0..population.len());
+        if population[candidate2].z > population[candidate1].z {
+            return candidate2;
+        }
+        candidate1
+    }
+
+    /// Replaces random points the child took from the second parent with random
+    /// points of the first parent it doesn't have until it respects the capacity
+    /// and the group limits. The first parent respects them, so in the worst case
+    /// the child becomes a copy of it
+    fn repair(&mut self, instance: &ProblemInstance, parent: &[usize], child: &mut [usize]) {
+        while !GeneticAlgorithm::to_solution(instance, child).is_feasible(instance, child.len()) {
+            let positions = (0..child.len())
+                .filter(|position| !parent.contains(&child[*position]))
+                .collect::<Vec<usize>>();
+            let parent_indexes = parent
+                .iter()
+                .filter(|index| !child.contains(index))
+                .collect::<Vec<&usize>>();
+            match (
+                positions.choose(&mut self.rng),
+                parent_indexes.choose(&mut self.rng),
+            ) {
+                (Some(position), Some(parent_index)) => child[*position] = **parent_index,
+                _ => break,
+            }
+        }
+        child.sort_unstable();
+    }
+
+    /// Replaces a random point of the solution that isn't fixed with a random point
+    /// outside it that isn't forbidden. If there isn't such a swap, or it doesn't
+    /// respect the capacity or the group limits, nothing is done
+    fn mutate(&mut self, instance: &ProblemInstance, indexes: &mut [usize]) {
+        let positions = (0..indexes.len())
+            .filter(|position| !instance.is_fixed_index(indexes[*position]))
+            .collect::<Vec<usize>>();
+        let new_indexes = (0..instance.points.len())
+            .filter(|index| !instance.is_forbidden_index(*index) && !indexes.contains(index))
+            .collect::<Vec<usize>>();
+        if let (Some(position), Some(new_index)) = (
+            positions.choose(&mut self.rng),
+            new_indexes.choose(&mut self.rng),
+        ) {
+            let solution = GeneticAlgorithm::to_solution(instance, indexes);
+            let weight = solution.get_weight();
+            let counts = instance.get_group_counts(&solution.points);
+            let point = &instance.points[*new_index];
+            if is_possible_swap(instance, &solution, weight, &counts, *position, point) {
+                indexes[*position] = *new_index;
+                indexes.sort_unstable();
+            }
+        }
+    }
+
+    /// Adds an individual to the population. While the population isn't full every
+    /// new individual is added, and then it replaces the most similar individual of
+    /// those worse than it. Repeated individuals are discarded
+    fn replace(&self, population: &mut Vec<Individual>, individual: Individual) {
+        if population
+            .iter()
+            .any(|other_individual| other_individual.indexes == individual.indexes)
+        {
+            return;
+        }
+        if population.len() < self.population_size {
+            population.push(individual);
+            return;
+        }
+        let most_similar_worse_individual = population
+            .iter()
+            .enumerate()
+            .filter(|(_, other_individual)| other_individual.z < individual.z)
+            .map(|(position, other_individual)| {
+                let common_points = other_individual
+                    .indexes
+                    .iter()
+                    .filter(|index| individual.indexes.contains(index))
+                    .count();
+                (position, common_points, other_individual.z)
+            })
+            .reduce(|acc, value| {
+                if value.1 > acc.1 || (value.1 == acc.1 && value.2 < acc.2) {
+                    return value;
+                }
+                acc
+            });
+        if let Some((position, _, _)) = most_similar_worse_individual {
+            population[position] = individual;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::problem_solver::{local_search::Swap, BranchAndBound};
+    #[test]
+    fn memetic_algorithm_reaches_optimum() {
+        let instance = ProblemInstance::from_file("problem_instances/max_div_15_2.txt").unwrap();
+        let optimum = BranchAndBound::new(4).solve(&instance);
+        let solution = GeneticAlgorithm::new(4, 10, 5)
+            .with_crossover(Crossover::Greedy)
+            .with_local_search(Swap::new())
+            .with_seed(2)
+            .solve(&instance);
+        assert!((solution.get_z() - optimum.get_z()).abs() < 1e-9);
+    }
+    #[test]
+    fn every_point() {
+        let instance = ProblemInstance::from_file("problem_instances/max_div_15_2.txt").unwrap();
+        let solution = GeneticAlgorithm::new(15, 10, 5)
+            .with_seed(2)
+            .solve(&instance);
+        assert_eq!(solution.get_points().len(), 15);
+    }
+    #[test]
+    fn fixed_and_forbidden_points() {
+        let instance = ProblemInstance::from_file("problem_instances/max_div_20_2.txt")
+            .unwrap()
+            .with_fixed_points(vec![0, 5])
+            .with_forbidden_points(vec![1, 2, 3]);
+        for crossover in [Crossover::Uniform, Crossover::Greedy] {
+            let solution = GeneticAlgorithm::new(4, 10, 5)
+                .with_crossover(crossover)
+                .with_mutation_probability(1.0)
+                .with_seed(2)
+                .solve(&instance);
+            assert!(solution.is_feasible(&instance, 4));
+        }
+    }
+    #[test]
+    fn capacity() {
+        let instance =
+            ProblemInstance::from_file("problem_instances/max_div_15_2_capacity.txt").unwrap();
+        for crossover in [Crossover::Uniform, Crossover::Greedy] {
+            let solution = GeneticAlgorithm::new(15, 10, 5)
+                .with_crossover(crossover)
+                .with_mutation_probability(1.0)
+                .with_seed(2)
+                .solve(&instance);
+            assert!(solution.is_feasible(&instance, 15));
+        }
+    }
+    #[test]
+    fn group_limits() {
+        let instance =
+            ProblemInstance::from_file("problem_instances/max_div_20_2_balanced.txt").unwrap();
+        for crossover in [Crossover::Uniform, Crossover::Greedy] {
+            let solution = GeneticAlgorithm::new(4, 10, 5)
+                .with_crossover(crossover)
+                .with_mutation_probability(1.0)
+                .with_seed(2)
+                .solve(&instance);
+            assert!(solution.is_feasible(&instance, 4));
+        }
+    }
+}
@@ -1,6 +1,6 @@
 use super::{
-    local_search::LocalSearch, ProblemInstance, ProblemSolution, ProblemSolver,
-    RandomizedGreedySolver,
+    local_search::LocalSearch, ElitePool, Incumbent, PathRelinking, ProblemInstance,
+    ProblemSolution, ProblemSolver, RandomizedGreedySolver, SelectionBias,
 };
 
 /// A implementation of a GRASP algorithm.  The local search to be used can
@@ -8,24 +8,39 @@ use super::{
 /// [Randomized greedy solver algorithm](super::RandomizedGreedySolver).
 /// The number of points in the solution and the size of the restricted candidate list
 pub struct GRASP<L: LocalSearch> {
-    number_of_points: usize,
-    rcl_size: usize,
+    constructive_solver: RandomizedGreedySolver,
     local_search: L,
     iterations: usize,
+    path_relinking: Option<(PathRelinking, usize)>,
+    incumbent: Option<Incumbent>,
 }
 
 impl<L: LocalSearch> ProblemSolver for GRASP<L> {
     fn solve(&mut self, instance: &ProblemInstance) -> ProblemSolution {
+        if let Some((path_relinking, elite_pool_size)) = self.path_relinking {
+            return self.solve_with_path_relinking(instance, path_relinking, elite_pool_size);
+        }
+        let incumbent = self.incumbent.clone();
         (0..self.iterations)
+            .take_while(|iteration| {
+                *iteration == 0
+                    || !incumbent
+                        .as_ref()
+                        .is_some_and(|incumbent| incumbent.is_stopped())
+            })
             .map(|_| {
-                let mut solver = RandomizedGreedySolver::new(self.number_of_points, self.rcl_size);
-                self.local_search.improve(instance, solver.solve(instance))
+                let solution = self.constructive_solver.solve(instance);
+                let solution = self.local_search.improve(instance, solution);
+                if let Some(incumbent) = &incumbent {
+                    incumbent.offer_solution(&solution);
+                }
+                solution
             })
             .reduce(|best_solution, solution| {
                 if solution.get_z() > best_solution.get_z() {
                     return solution;
                 }
-                return best_solution;
+                best_solution
             })
             .unwrap()
     }
@@ -39,12 +54,89 @@ impl<L: LocalSearch> GRASP<L> {
         local_search: L,
         iterations: usize,
     ) -> Self {
-        assert!(number_of_points > 0 && rcl_size > 0);
         GRASP {
-            number_of_points,
-            rcl_size,
+            constructive_solver: RandomizedGreedySolver::new(number_of_points, rcl_size),
             local_search,
             iterations,
+            path_relinking: None,
+            incumbent: None,
+        }
+    }
+
+    /// Seeds the random number generator of the constructive phase, so the
+    /// solutions are reproducible
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.constructive_solver = self.constructive_solver.with_seed(seed);
+        self
+    }
+
+    /// Uses a value based restricted candidate list with the specified alpha in the
+    /// constructive phase instead of a fixed size
+    pub fn with_alpha(mut self, alpha: f64) -> Self {
+        self.constructive_solver = self.constructive_solver.with_alpha(alpha);
+        self
+    }
+
+    /// Sets how the candidates of the constructive phase are weighted by their rank
+    pub fn with_selection_bias(mut self, selection_bias: SelectionBias) -> Self {
+        self.constructive_solver = self.constructive_solver.with_selection_bias(selection_bias);
+        self
+    }
+
+    /// Adds a path relinking phase to each iteration. The solutions found are kept in
+    /// an [elite pool](super::ElitePool) of the specified size, and the solution of each
+    /// iteration is relinked with the most different solution of the pool. The result
+    /// is improved with the local search and both solutions are offered to the pool
+    pub fn with_path_relinking(
+        mut self,
+        path_relinking: PathRelinking,
+        elite_pool_size: usize,
+    ) -> Self {
+        assert!(elite_pool_size > 0);
+        self.path_relinking = Some((path_relinking, elite_pool_size));
+        self
+    }
+
+    /// Offers the solution of each iteration to an [incumbent](super::Incumbent)
+    /// shared with other solvers, and stops after the current iteration when the
+    /// incumbent is stopped
+    pub fn with_incumbent(mut self, incumbent: Incumbent) -> Self {
+        self.incumbent = Some(incumbent);
+        self
+    }
+
+    fn solve_with_path_relinking(
+        &mut self,
+        instance: &ProblemInstance,
+        path_relinking: PathRelinking,
+        elite_pool_size: usize,
+    ) -> ProblemSolution {
+        let mut elite_pool = ElitePool::new(elite_pool_size);
+        for iteration in 0..self.iterations {
+            let stopped = self
+                .incumbent
+                .as_ref()
+                .is_some_and(|incumbent| incumbent.is_stopped());
+            if iteration > 0 && stopped {
+                break;
+            }
+            let solution = self.constructive_solver.solve(instance);
+            let solution = self.local_search.improve(instance, solution);
+            if let Some(incumbent) = &self.incumbent {
+                incumbent.offer_solution(&solution);
+            }
+            if let Some(guiding_solution) = elite_pool.get_most_different_solution(&solution) {
+                let relinked_solution =
+                    path_relinking.relink(instance, &solution, guiding_solution);
+                let relinked_solution = self.local_search.improve(instance, relinked_solution);
+                if let Some(incumbent) = &self.incumbent {
+                    incumbent.offer_solution(&relinked_solution);
+                }
+                elite_pool.offer_solution(relinked_solution);
+            }
+            elite_pool.offer_solution(solution);
         }
+        // The pool always gets the solution of the first iteration
+        elite_pool.get_best_solution().unwrap().clone()
     }
 }
@@ -0,0 +1,121 @@
+use super::{Point, ProblemInstance, ScoringFunction};
+
+/// The [scoring function](super::ScoringFunction) the [greedy solver](super::GreedySolver)
+/// and the [randomized greedy solver](super::RandomizedGreedySolver) use to score the
+/// points in each step. The constructive criteria add the points with the best
+/// scores one by one, and the destructive one starts with all the points and
+/// drops the one with the worst score until the solution has the right size
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum GreedyCriterion {
+    /// The score of a point is its distance to the center of the points already in
+    /// the solution, or to the center of all the points for the first one
+    #[default]
+    Centroid,
+    /// The score of a point is the sum of its distances to the points already in
+    /// the solution. The first point is the farthest from the center of all the points
+    MaxSum,
+    /// The score of a point is its distance to the nearest point already in the
+    /// solution. The first point is the farthest from the center of all the points
+    MaxMin,
+    /// The farthest-first traversal of Gonzalez. It scores the points like max-min,
+    /// but all the points have the same score for the first one, so it is an
+    /// arbitrary point in the greedy solver and a random one in the randomized one.
+    /// It is kept apart from max-min because the traversal doesn't depend on the
+    /// center, so the randomized solver can start it from any point
+    FarthestFirst,
+    /// Starts with all the points and in each step drops the one with the smallest
+    /// sum of distances to the rest of the points that remain
+    Destructive,
+}
+
+impl ScoringFunction for GreedyCriterion {
+    fn get_scores(
+        &self,
+        instance: &ProblemInstance,
+        candidates: &[Point],
+        solution: &[Point],
+    ) -> Vec<f64> {
+        if solution.is_empty() {
+            if *self == GreedyCriterion::FarthestFirst {
+                return vec![0.0; candidates.len()];
+            }
+            let center = calculate_center(&instance.points);
+            return candidates
+                .iter()
+                .map(|point| point.distance_to(&center))
+                .collect();
+        }
+        let center = match self {
+            GreedyCriterion::Centroid => Some(calculate_center(solution)),
+            _ => None,
+        };
+        candidates
+            .iter()
+            .map(|candidate| {
+                let distances = solution.iter().map(|point| point.distance_to(candidate));
+                match (self, &center) {
+                    (GreedyCriterion::Centroid, Some(center)) => candidate.distance_to(center),
+                    (GreedyCriterion::MaxSum, _) => distances.sum(),
+                    (GreedyCriterion::Destructive, _) => -distances.sum::<f64>(),
+                    _ => distances.fold(f64::INFINITY, f64::min),
+                }
+            })
+            .collect()
+    }
+
+    fn is_destructive(&self) -> bool {
+        *self == GreedyCriterion::Destructive
+    }
+}
+
+/// Calculates the center of the points, which must not be empty
+pub(super) fn calculate_center(points: &[Point]) -> Point {
+    let coordinates = (0..points[0].get_dimensionality())
+        .map(|index| {
+            points
+                .iter()
+                .map(|point| point.coordinates[index])
+                .sum::<f64>()
+                / points.len() as f64
+        })
+        .collect();
+    Point::new(coordinates)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn center() {
+        let center = calculate_center(&[
+            Point::new(vec![0.0, 0.0]),
+            Point::new(vec![2.0, 0.0]),
+            Point::new(vec![0.0, 2.0]),
+            Point::new(vec![2.0, 2.0]),
+        ]);
+        assert_eq!((center.coordinates[0], center.coordinates[1]), (1.0, 1.0));
+    }
+    #[test]
+    fn max_min_and_farthest_first_differ_in_the_first_point() {
+        let points = vec![
+            Point::new(vec![0.0, 0.0]),
+            Point::new(vec![2.0, 0.0]),
+            Point::new(vec![0.0, 2.0]),
+            Point::new(vec![4.0, 4.0]),
+        ];
+        let instance = ProblemInstance::new(points.clone());
+        let max_min_scores = GreedyCriterion::MaxMin.get_scores(&instance, &points, &[]);
+        assert_eq!(
+            max_min_scores[3],
+            max_min_scores.iter().cloned().fold(0.0, f64::max)
+        );
+        let farthest_first_scores =
+            GreedyCriterion::FarthestFirst.get_scores(&instance, &points, &[]);
+        assert!(farthest_first_scores.iter().all(|score| *score == 0.0));
+        let solution = [points[0].clone()];
+        assert_eq!(
+            GreedyCriterion::MaxMin.get_scores(&instance, &points, &solution),
+            GreedyCriterion::FarthestFirst.get_scores(&instance, &points, &solution)
+        );
+    }
+}
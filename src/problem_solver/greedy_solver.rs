@@ -1,26 +1,17 @@
-use super::{Point, ProblemInstance, ProblemSolution, ProblemSolver};
+use super::{ConstructiveSolver, GreedyCriterion, ProblemInstance, ProblemSolution, ProblemSolver};
 
 /// A greedy algorithm that creates a solution for the problem by adding
 /// to the solution in each step the point which is further from the center
-/// of the points already in the solution
+/// of the points already in the solution. Other
+/// [criteria](super::GreedyCriterion) can be used instead. It is a
+/// [constructive solver](super::ConstructiveSolver) that always chooses the best candidate
 pub struct GreedySolver {
-    number_of_points: usize,
+    constructive_solver: ConstructiveSolver,
 }
 
 impl ProblemSolver for GreedySolver {
     fn solve(&mut self, instance: &ProblemInstance) -> ProblemSolution {
-        assert!(instance.points.len() >= self.number_of_points);
-        let mut points = instance.points.clone();
-        let mut center = GreedySolver::calculate_center(&points);
-        let mut solution_points = Vec::new();
-        while solution_points.len() < self.number_of_points {
-            let new_point = GreedySolver::get_farthest_point(&mut points, &center);
-            solution_points.push(new_point);
-            center = GreedySolver::calculate_center(&solution_points);
-        }
-        ProblemSolution {
-            points: solution_points,
-        }
+        self.constructive_solver.solve(instance)
     }
 }
 
@@ -28,84 +19,56 @@ impl GreedySolver {
     /// Creates a new solver. The number of points the solution will have needs to
     /// be passed as argument
     pub fn new(number_of_points: usize) -> Self {
-        assert!(number_of_points > 0);
-        GreedySolver { number_of_points }
-    }
-
-    fn calculate_center(points: &Vec<Point>) -> Point {
-        let coordinates = (0..points[0].get_dimensionality())
-            .map(|index| {
-                points
-                    .iter()
-                    .map(|point| point.coordinates[index])
-                    .sum::<f64>()
-                    / points.len() as f64
-            })
-            .collect();
-        Point::new(coordinates)
+        GreedySolver {
+            constructive_solver: ConstructiveSolver::new(
+                number_of_points,
+                GreedyCriterion::default(),
+            ),
+        }
     }
 
-    fn get_farthest_point(available_points: &mut Vec<Point>, center: &Point) -> Point {
-        available_points.remove(
-            available_points
-                .iter()
-                .map(|point| point.distance_to(center))
-                .enumerate()
-                .reduce(|acc, value| {
-                    if value.1 > acc.1 {
-                        return value;
-                    }
-                    return acc;
-                })
-                // There must be at least one point in the vector
-                .unwrap()
-                .0,
-        )
+    /// Sets the criterion used to choose the points. By default it is the
+    /// distance to the center of the solution
+    pub fn with_criterion(mut self, criterion: GreedyCriterion) -> Self {
+        self.constructive_solver = self.constructive_solver.with_scoring_function(criterion);
+        self
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::problem_solver::{ScoringFunction, SelectionRule};
+    use crate::Point;
     #[test]
-    fn center() {
-        let center = GreedySolver::calculate_center(&vec![
-            Point {
-                coordinates: vec![0.0, 0.0],
-            },
-            Point {
-                coordinates: vec![2.0, 0.0],
-            },
-            Point {
-                coordinates: vec![0.0, 2.0],
-            },
-            Point {
-                coordinates: vec![2.0, 2.0],
-            },
-        ]);
-        assert_eq!((center.coordinates[0], center.coordinates[1]), (1.0, 1.0));
+    fn furthest_point() {
+        let points = vec![
+            Point::new(vec![0.0, 0.0]),
+            Point::new(vec![3.0, 0.0]),
+            Point::new(vec![1.0, 2.0]),
+            Point::new(vec![2.0, 2.0]),
+        ];
+        // The instance is only used to score the first point
+        let instance = ProblemInstance::new(points.clone());
+        let solution = [Point::new(vec![1.0, 1.0])];
+        let scores = GreedyCriterion::Centroid.get_scores(&instance, &points, &solution);
+        let mut rng = rand::thread_rng();
+        assert_eq!(SelectionRule::Best.select(&scores, &mut rng), 1);
     }
     #[test]
-    fn furthest_point() {
-        let point = GreedySolver::get_farthest_point(
-            &mut vec![
-                Point {
-                    coordinates: vec![0.0, 0.0],
-                },
-                Point {
-                    coordinates: vec![3.0, 0.0],
-                },
-                Point {
-                    coordinates: vec![1.0, 2.0],
-                },
-                Point {
-                    coordinates: vec![2.0, 2.0],
-                },
-            ],
-            &Point {
-                coordinates: vec![1.0, 1.0],
-            },
-        );
-        assert_eq!((point.coordinates[0], point.coordinates[1]), (3.0, 0.0));
+    fn criteria() {
+        let instance = ProblemInstance::from_file("problem_instances/max_div_20_2.txt").unwrap();
+        for criterion in [
+            GreedyCriterion::Centroid,
+            GreedyCriterion::MaxSum,
+            GreedyCriterion::MaxMin,
+            GreedyCriterion::FarthestFirst,
+            GreedyCriterion::Destructive,
+        ] {
+            let solution = GreedySolver::new(5)
+                .with_criterion(criterion)
+                .solve(&instance);
+            assert_eq!(solution.get_points().len(), 5);
+        }
     }
 }
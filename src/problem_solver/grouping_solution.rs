@@ -0,0 +1,34 @@
+use std::fmt;
+
+use super::ProblemSolution;
+
+/// A solution of the maximally diverse grouping problem. Every point of the
+/// instance belongs to one of its groups, which are represented as solutions
+#[derive(Clone)]
+pub struct GroupingSolution {
+    pub(super) groups: Vec<ProblemSolution>,
+}
+
+impl GroupingSolution {
+    /// Gets the z, which is the sum of the z of the groups
+    pub fn get_z(&self) -> f64 {
+        self.groups.iter().map(|group| group.get_z()).sum()
+    }
+
+    /// Gets the groups of the solution
+    pub fn get_groups(&self) -> &[ProblemSolution] {
+        &self.groups
+    }
+}
+
+impl fmt::Display for GroupingSolution {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let string = self
+            .groups
+            .iter()
+            .map(|group| group.to_string())
+            .collect::<Vec<String>>()
+            .join(", ");
+        write!(f, "[{}]", string)
+    }
+}
@@ -0,0 +1,282 @@
+use super::{GroupingSolution, ProblemInstance, ProblemSolution};
+use rand::{rngs::StdRng, seq::SliceRandom, SeedableRng};
+
+/// The smallest change of z that counts as an improvement, so the rounding errors
+/// of the contributions can't make the local search cycle
+const MIN_IMPROVEMENT: f64 = 1e-9;
+
+/// A solver for the maximally diverse grouping problem, which splits every point of
+/// the instance into the specified number of groups, each with a number of points
+/// between a minimum and a maximum, maximizing the sum of the z of the groups. In
+/// each iteration the points are assigned in random order to the group where they
+/// add the most, leaving room for the minimums of the groups, and the result is
+/// improved with a local search that moves a point to another group or swaps two
+/// points of different groups. The best grouping of all the iterations is returned.
+/// The groups the points of the instance belong to aren't used, and the instance
+/// can't have fixed or forbidden points, a capacity or group limits
+pub struct GroupingSolver {
+    number_of_groups: usize,
+    min_points: usize,
+    max_points: usize,
+    iterations: usize,
+    rng: StdRng,
+}
+
+/// A grouping being built or improved. It stores the group of each point, the
+/// size of each group and the sum of the distances from each point to the points
+/// of each group
+struct Grouping {
+    groups: Vec<Option<usize>>,
+    sizes: Vec<usize>,
+    contributions: Vec<Vec<f64>>,
+}
+
+impl GroupingSolver {
+    /// Creates a new solver with the specified arguments. The number of groups, the
+    /// minimum and maximum number of points of each group and the number of
+    /// iterations
+    pub fn new(
+        number_of_groups: usize,
+        min_points: usize,
+        max_points: usize,
+        iterations: usize,
+    ) -> Self {
+        assert!(number_of_groups > 0 && max_points > 0 && min_points <= max_points);
+        assert!(iterations > 0);
+        GroupingSolver {
+            number_of_groups,
+            min_points,
+            max_points,
+            iterations,
+            rng: StdRng::from_entropy(),
+        }
+    }
+
+    /// Seeds the random number generator, so the solutions are reproducible
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.rng = StdRng::seed_from_u64(seed);
+        self
+    }
+
+    /// Splits the points of the instance into groups
+    pub fn solve(&mut self, instance: &ProblemInstance) -> GroupingSolution {
+        let n = instance.points.len();
+        assert!(
+            self.number_of_groups * self.min_points <= n
+                && n <= self.number_of_groups.saturating_mul(self.max_points)
+        );
+        assert!(
+            instance.get_fixed_points().is_empty() && instance.get_forbidden_points().is_empty()
+        );
+        assert!(instance.get_capacity().is_none() && instance.group_limits.is_empty());
+        let distances = instance
+            .points
+            .iter()
+            .map(|point| {
+                instance
+                    .points
+                    .iter()
+                    .map(|other_point| point.distance_to(other_point))
+                    .collect()
+            })
+            .collect::<Vec<Vec<f64>>>();
+        let (groups, _) = (0..self.iterations)
+            .map(|_| {
+                let mut grouping = self.construct(&distances);
+                self.improve(&mut grouping, &distances);
+                let z = grouping.get_z();
+                (grouping.groups, z)
+            })
+            .reduce(|acc, value| {
+                if value.1 > acc.1 {
+                    return value;
+                }
+                acc
+            })
+            // There is at least one iteration
+            .unwrap();
+        GroupingSolution {
+            groups: (0..self.number_of_groups)
+                .map(|group| ProblemSolution {
+                    points: (0..n)
+                        .filter(|point| groups[*point] == Some(group))
+                        .map(|point| instance.points[point].clone())
+                        .collect(),
+                })
+                .collect(),
+        }
+    }
+
+    /// Assigns the points in random order. The first ones start a group each and
+    /// the rest go to the group where they add the most among the ones that aren't
+    /// full. When the points left are only enough for the minimums, they can only go
+    /// to the groups below their minimum
+    fn construct(&mut self, distances: &[Vec<f64>]) -> Grouping {
+        let n = distances.len();
+        let mut grouping = Grouping {
+            groups: vec![None; n],
+            sizes: vec![0; self.number_of_groups],
+            contributions: vec![vec![0.0; self.number_of_groups]; n],
+        };
+        let mut points = (0..n).collect::<Vec<usize>>();
+        points.shuffle(&mut self.rng);
+        for (position, point) in points.into_iter().enumerate() {
+            let missing_points = grouping
+                .sizes
+                .iter()
+                .map(|size| self.min_points.saturating_sub(*size))
+                .sum::<usize>();
+            let only_minimums = n - position == missing_points;
+            let group = if position < self.number_of_groups {
+                position
+            } else {
+                (0..self.number_of_groups)
+                    .filter(|group| {
+                        let size = grouping.sizes[*group];
+                        size < self.max_points && (!only_minimums || size < self.min_points)
+                    })
+                    .reduce(|acc, group| {
+                        if grouping.contributions[point][group] > grouping.contributions[point][acc]
+                        {
+                            return group;
+                        }
+                        acc
+                    })
+                    // The number of points fits in the groups
+                    .unwrap()
+            };
+            grouping.assign(point, group, distances);
+        }
+        grouping
+    }
+
+    /// Performs the first improving move or swap until there isn't any. A point can
+    /// only move if its group stays above its minimum and the other group below its
+    /// maximum
+    fn improve(&self, grouping: &mut Grouping, distances: &[Vec<f64>]) {
+        let n = distances.len();
+        let mut improved = true;
+        while improved {
+            improved = false;
+            for point in 0..n {
+                // Every point has been assigned
+                let group = grouping.groups[point].unwrap();
+                let contributions = &grouping.contributions[point];
+                let new_group = (0..self.number_of_groups).find(|new_group| {
+                    *new_group != group
+                        && grouping.sizes[group] > self.min_points
+                        && grouping.sizes[*new_group] < self.max_points
+                        && contributions[*new_group] - contributions[group] > MIN_IMPROVEMENT
+                });
+                if let Some(new_group) = new_group {
+                    grouping.unassign(point, distances);
+                    grouping.assign(point, new_group, distances);
+                    improved = true;
+                    continue;
+                }
+                let other_point = (point + 1..n).find(|other_point| {
+                    let other_group = grouping.groups[*other_point].unwrap();
+                    let other_contributions = &grouping.contributions[*other_point];
+                    other_group != group
+                        && contributions[other_group] + other_contributions[group]
+                            - contributions[group]
+                            - other_contributions[other_group]
+                            - 2.0 * distances[point][*other_point]
+                            > MIN_IMPROVEMENT
+                });
+                if let Some(other_point) = other_point {
+                    let other_group = grouping.groups[other_point].unwrap();
+                    grouping.unassign(point, distances);
+                    grouping.unassign(other_point, distances);
+                    grouping.assign(point, other_group, distances);
+                    grouping.assign(other_point, group, distances);
+                    improved = true;
+                }
+            }
+        }
+    }
+}
+
+impl Grouping {
+    /// Adds the point to the specified group
+    fn assign(&mut self, point: usize, group: usize, distances: &[Vec<f64>]) {
+        self.groups[point] = Some(group);
+        self.sizes[group] += 1;
+        for (contributions, distance) in self.contributions.iter_mut().zip(&distances[point]) {
+            contributions[group] += distance;
+        }
+    }
+
+    /// Removes the point from its group
+    fn unassign(&mut self, point: usize, distances: &[Vec<f64>]) {
+        // Only the assigned points are removed
+        let group = self.groups[point].take().unwrap();
+        self.sizes[group] -= 1;
+        for (contributions, distance) in self.contributions.iter_mut().zip(&distances[point]) {
+            contributions[group] -= distance;
+        }
+    }
+
+    /// Gets the sum of the z of the groups. Each distance is counted by both points
+    fn get_z(&self) -> f64 {
+        self.groups
+            .iter()
+            .enumerate()
+            .filter_map(|(point, group)| group.map(|group| self.contributions[point][group]))
+            .sum::<f64>()
+            / 2.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn groups_every_point_within_the_limits() {
+        let instance = ProblemInstance::from_file("problem_instances/max_div_20_2.txt").unwrap();
+        let solution = GroupingSolver::new(3, 6, 7, 5)
+            .with_seed(1)
+            .solve(&instance);
+        let groups = solution.get_groups();
+        assert_eq!(groups.len(), 3);
+        assert!(groups
+            .iter()
+            .all(|group| (6..=7).contains(&group.get_points().len())));
+        assert!(instance
+            .points
+            .iter()
+            .all(|point| groups.iter().any(|group| group.points.contains(point))));
+        assert_eq!(
+            groups.iter().map(|group| group.points.len()).sum::<usize>(),
+            20
+        );
+    }
+    #[test]
+    fn reaches_optimum_of_small_instance() {
+        let instance = ProblemInstance::from_file("problem_instances/max_div_15_2.txt").unwrap();
+        let instance = ProblemInstance::new(instance.points[..9].to_vec());
+        let optimal_z = (0..3usize.pow(9))
+            .map(|code| {
+                (0..9)
+                    .map(|point| code / 3usize.pow(point) % 3)
+                    .collect::<Vec<usize>>()
+            })
+            .filter(|groups| {
+                (0..3).all(|group| groups.iter().filter(|g| **g == group).count() == 3)
+            })
+            .map(|groups| {
+                (0..9)
+                    .flat_map(|point| (point + 1..9).map(move |other_point| (point, other_point)))
+                    .filter(|(point, other_point)| groups[*point] == groups[*other_point])
+                    .map(|(point, other_point)| {
+                        instance.points[point].distance_to(&instance.points[other_point])
+                    })
+                    .sum::<f64>()
+            })
+            .fold(0.0, f64::max);
+        let solution = GroupingSolver::new(3, 3, 3, 10)
+            .with_seed(1)
+            .solve(&instance);
+        assert!((solution.get_z() - optimal_z).abs() < 1e-9);
+    }
+}
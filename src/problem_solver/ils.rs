@@ -0,0 +1,181 @@
+use super::perturbation::perform_random_swaps;
+use super::{
+    local_search::LocalSearch, AcceptanceCriterion, Incumbent, ProblemInstance, ProblemSolution,
+    ProblemSolver,
+};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+/// A implementation of an iterated local search. The initial solution is created
+/// by the constructive solver passed to the constructor and improved with the
+/// local search. In each iteration the actual solution is perturbed by performing
+/// some random swaps, the result is improved with the local search and the
+/// [acceptance criterion](super::AcceptanceCriterion) decides if the search moves
+/// to it. The best solution found is returned
+pub struct ILS<C: ProblemSolver, L: LocalSearch> {
+    constructive_solver: C,
+    local_search: L,
+    perturbation_strength: usize,
+    iterations: usize,
+    acceptance_criterion: AcceptanceCriterion,
+    rng: StdRng,
+    incumbent: Option<Incumbent>,
+}
+
+impl<C: ProblemSolver, L: LocalSearch> ProblemSolver for ILS<C, L> {
+    fn solve(&mut self, instance: &ProblemInstance) -> ProblemSolution {
+        let solution = self.constructive_solver.solve(instance);
+        let mut actual_solution = self.local_search.improve(instance, solution);
+        let mut best_solution = actual_solution.clone();
+        if let Some(incumbent) = &self.incumbent {
+            incumbent.offer_solution(&best_solution);
+        }
+        let mut temperature = self.acceptance_criterion.get_initial_temperature();
+        for _ in 0..self.iterations {
+            if self.is_stopped() {
+                break;
+            }
+            let solution = perform_random_swaps(
+                instance,
+                actual_solution.clone(),
+                self.perturbation_strength,
+                &mut self.rng,
+            );
+            let solution = self.local_search.improve(instance, solution);
+            let delta = solution.get_z() - actual_solution.get_z();
+            if self
+                .acceptance_criterion
+                .accepts(delta, temperature, self.rng.gen())
+            {
+                if solution.get_z() > best_solution.get_z() {
+                    best_solution = solution.clone();
+                    if let Some(incumbent) = &self.incumbent {
+                        incumbent.offer_solution(&best_solution);
+                    }
+                }
+                actual_solution = solution;
+            }
+            temperature = self.acceptance_criterion.cool(temperature);
+        }
+        best_solution
+    }
+}
+
+impl<C: ProblemSolver, L: LocalSearch> ILS<C, L> {
+    /// Creates a new ILS with the specified arguments. The solver that creates the
+    /// initial solution, the local search, the number of random swaps of each
+    /// perturbation and the number of iterations
+    pub fn new(
+        constructive_solver: C,
+        local_search: L,
+        perturbation_strength: usize,
+        iterations: usize,
+    ) -> Self {
+        assert!(perturbation_strength > 0);
+        ILS {
+            constructive_solver,
+            local_search,
+            perturbation_strength,
+            iterations,
+            acceptance_criterion: AcceptanceCriterion::default(),
+            rng: StdRng::from_entropy(),
+            incumbent: None,
+        }
+    }
+
+    /// Sets the criterion used to decide if the search moves to a new solution.
+    /// By default it only moves to better solutions
+    pub fn with_acceptance_criterion(mut self, acceptance_criterion: AcceptanceCriterion) -> Self {
+        if let AcceptanceCriterion::SimulatedAnnealing {
+            initial_temperature,
+            cooling_rate,
+        } = acceptance_criterion
+        {
+            assert!(initial_temperature >= 0.0 && cooling_rate > 0.0 && cooling_rate < 1.0);
+        }
+        self.acceptance_criterion = acceptance_criterion;
+        self
+    }
+
+    /// Seeds the random number generator of the perturbations, so the solutions are
+    /// reproducible as long as the constructive solver is deterministic or seeded too
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.rng = StdRng::seed_from_u64(seed);
+        self
+    }
+
+    /// Offers the improvements of the search to an [incumbent](super::Incumbent)
+    /// shared with other solvers, and stops as soon as the incumbent is stopped,
+    /// returning the best solution found so far
+    pub fn with_incumbent(mut self, incumbent: Incumbent) -> Self {
+        self.incumbent = Some(incumbent);
+        self
+    }
+
+    /// Whether the incumbent shared with other solvers has been stopped
+    fn is_stopped(&self) -> bool {
+        self.incumbent
+            .as_ref()
+            .is_some_and(|incumbent| incumbent.is_stopped())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::problem_solver::{local_search::Swap, BranchAndBound, GreedySolver};
+    #[test]
+    fn never_worse_than_the_initial_local_optimum() {
+        let instance = ProblemInstance::from_file("problem_instances/max_div_20_2.txt").unwrap();
+        let initial_solution =
+            Swap::new().improve(&instance, GreedySolver::new(4).solve(&instance));
+        for acceptance_criterion in [
+            AcceptanceCriterion::Better,
+            AcceptanceCriterion::RandomWalk,
+            AcceptanceCriterion::SimulatedAnnealing {
+                initial_temperature: 10.0,
+                cooling_rate: 0.9,
+            },
+        ] {
+            let solution = ILS::new(GreedySolver::new(4), Swap::new(), 2, 20)
+                .with_acceptance_criterion(acceptance_criterion)
+                .with_seed(3)
+                .solve(&instance);
+            assert_eq!(solution.get_points().len(), 4);
+            assert!(solution.get_z() >= initial_solution.get_z());
+        }
+    }
+    #[test]
+    fn fixed_and_forbidden_points() {
+        let instance = ProblemInstance::from_file("problem_instances/max_div_20_2.txt")
+            .unwrap()
+            .with_fixed_points(vec![0, 5])
+            .with_forbidden_points(vec![1, 2, 3]);
+        let solution = ILS::new(GreedySolver::new(4), Swap::new(), 3, 20)
+            .with_acceptance_criterion(AcceptanceCriterion::RandomWalk)
+            .with_seed(3)
+            .solve(&instance);
+        assert!(solution.is_feasible(&instance, 4));
+    }
+    #[test]
+    fn capacity() {
+        let instance =
+            ProblemInstance::from_file("problem_instances/max_div_15_2_capacity.txt").unwrap();
+        let optimum = BranchAndBound::new(15).solve(&instance);
+        let solution = ILS::new(GreedySolver::new(15), Swap::new(), 3, 20)
+            .with_acceptance_criterion(AcceptanceCriterion::RandomWalk)
+            .with_seed(3)
+            .solve(&instance);
+        assert!(solution.is_feasible(&instance, 15));
+        assert!(solution.get_z() <= optimum.get_z() + 1e-9);
+    }
+    #[test]
+    fn group_limits() {
+        let instance =
+            ProblemInstance::from_file("problem_instances/max_div_20_2_balanced.txt").unwrap();
+        let solution = ILS::new(GreedySolver::new(4), Swap::new(), 3, 20)
+            .with_acceptance_criterion(AcceptanceCriterion::RandomWalk)
+            .with_seed(3)
+            .solve(&instance);
+        assert!(solution.is_feasible(&instance, 4));
+    }
+}
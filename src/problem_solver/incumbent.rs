@@ -0,0 +1,101 @@
+use super::ProblemSolution;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+
+/// The best solution found by a group of solvers running at the same time, like
+/// the ones of a [portfolio](super::Portfolio). It can be cloned to give a handle to
+/// each solver. The solvers that support it offer the solutions they find, and
+/// [branch and bound](super::BranchAndBound) uses it as its lower bound. It can
+/// also be stopped, which makes those solvers return as soon as possible
+#[derive(Clone)]
+pub struct Incumbent {
+    shared: Arc<SharedIncumbent>,
+    source: usize,
+}
+
+struct SharedIncumbent {
+    best_solution: Mutex<Option<(ProblemSolution, usize)>>,
+    best_z: AtomicU64,
+    stopped: AtomicBool,
+}
+
+impl Default for Incumbent {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Incumbent {
+    /// Creates an incumbent without a solution
+    pub fn new() -> Self {
+        Incumbent {
+            shared: Arc::new(SharedIncumbent {
+                best_solution: Mutex::new(None),
+                best_z: AtomicU64::new(f64::NEG_INFINITY.to_bits()),
+                stopped: AtomicBool::new(false),
+            }),
+            source: 0,
+        }
+    }
+
+    /// Gets a handle to the same incumbent that identifies the solutions it offers
+    /// with the specified source
+    pub(super) fn with_source(&self, source: usize) -> Self {
+        Incumbent {
+            shared: Arc::clone(&self.shared),
+            source,
+        }
+    }
+
+    /// Replaces the best solution if the given one is better and returns whether
+    /// it was replaced
+    pub fn offer_solution(&self, solution: &ProblemSolution) -> bool {
+        let z = solution.get_z();
+        if z <= self.get_z() {
+            return false;
+        }
+        let mut best_solution = self.shared.best_solution.lock().unwrap();
+        if z <= self.get_z() {
+            return false;
+        }
+        *best_solution = Some((solution.clone(), self.source));
+        self.shared.best_z.store(z.to_bits(), Ordering::Release);
+        true
+    }
+
+    /// The z of the best solution, or negative infinity if there isn't one yet. It
+    /// doesn't need to lock the solution, so it can be checked often
+    pub fn get_z(&self) -> f64 {
+        f64::from_bits(self.shared.best_z.load(Ordering::Acquire))
+    }
+
+    /// The best solution offered so far
+    pub fn get_best_solution(&self) -> Option<ProblemSolution> {
+        self.shared
+            .best_solution
+            .lock()
+            .unwrap()
+            .as_ref()
+            .map(|(solution, _)| solution.clone())
+    }
+
+    /// The source of the best solution offered so far
+    pub(super) fn get_source(&self) -> Option<usize> {
+        self.shared
+            .best_solution
+            .lock()
+            .unwrap()
+            .as_ref()
+            .map(|(_, source)| *source)
+    }
+
+    /// Asks the solvers that use this incumbent to stop
+    pub fn stop(&self) {
+        self.shared.stopped.store(true, Ordering::Release);
+    }
+
+    /// Whether the solvers that use this incumbent have been asked to stop
+    pub fn is_stopped(&self) -> bool {
+        self.shared.stopped.load(Ordering::Acquire)
+    }
+}
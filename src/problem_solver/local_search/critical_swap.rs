@@ -0,0 +1,67 @@
+use super::*;
+
+/// A local search that only considers swapping the critical point of the solution,
+/// which is the one that isn't fixed with the lowest contribution to the z. It is
+/// replaced with the point outside the solution that contributes the most to the rest
+/// of the points, so each step is much cheaper than evaluating the whole
+/// [Swap](super::Swap) environment
+pub struct CriticalSwap {}
+
+impl LocalSearch for CriticalSwap {
+    fn perform_search(
+        &self,
+        instance: &ProblemInstance,
+        solution: ProblemSolution,
+    ) -> ProblemSolution {
+        let critical_index = get_swappable_indexes(instance, &solution)
+            .map(|index| (index, get_contribution(&solution, &solution.points[index])))
+            .reduce(|min, actual| {
+                if actual.1 < min.1 {
+                    return actual;
+                }
+                min
+            })
+            .map(|(index, _)| index);
+        let critical_index = match critical_index {
+            Some(critical_index) => critical_index,
+            None => return solution,
+        };
+        let critical_point = &solution.points[critical_index];
+        let remaining_weight = solution.get_weight() - critical_point.weight;
+        let counts = instance.get_group_counts(&solution.points);
+        let best_point = get_points_outside_solution(instance, &solution)
+            .filter(|point| {
+                instance.fits(remaining_weight + point.weight)
+                    && instance.can_exchange_groups(
+                        &counts,
+                        &[critical_point.group],
+                        &[point.group],
+                    )
+            })
+            .map(|point| (point, get_swap_delta(&solution, critical_index, point)))
+            .filter(|(_, delta)| *delta > 0.0)
+            .reduce(|max, actual| {
+                if actual.1 > max.1 {
+                    return actual;
+                }
+                max
+            })
+            .map(|(point, _)| point.clone());
+        match best_point {
+            Some(point) => get_solution_after_swap(solution, critical_index, point),
+            None => solution,
+        }
+    }
+}
+
+impl Default for CriticalSwap {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CriticalSwap {
+    pub fn new() -> Self {
+        CriticalSwap {}
+    }
+}
@@ -0,0 +1,124 @@
+use super::*;
+
+/// A local search that consists on swapping two points that are in the solution
+/// with two points that aren't at the same time. It performs the best double swap
+/// of the environment, so it can escape the local optimums of [Swap](super::Swap),
+/// but the environment is quadratically bigger
+pub struct DoubleSwap {}
+
+impl LocalSearch for DoubleSwap {
+    fn perform_search(
+        &self,
+        instance: &ProblemInstance,
+        solution: ProblemSolution,
+    ) -> ProblemSolution {
+        let outside_points = get_points_outside_solution(instance, &solution).collect::<Vec<_>>();
+        let indexes = get_swappable_indexes(instance, &solution).collect::<Vec<_>>();
+        let weight = solution.get_weight();
+        let counts = instance.get_group_counts(&solution.points);
+        let mut best_move = None;
+        let mut best_delta = 0.0;
+        for (position, first_index) in indexes.iter().copied().enumerate() {
+            for second_index in indexes[position + 1..].iter().copied() {
+                let leaving_points = (
+                    &solution.points[first_index],
+                    &solution.points[second_index],
+                );
+                let remaining_weight = weight - leaving_points.0.weight - leaving_points.1.weight;
+                let leaving_groups = [leaving_points.0.group, leaving_points.1.group];
+                for (first_point_index, first_point) in outside_points.iter().enumerate() {
+                    for second_point in &outside_points[first_point_index + 1..] {
+                        let entering_groups = [first_point.group, second_point.group];
+                        if !instance
+                            .fits(remaining_weight + first_point.weight + second_point.weight)
+                            || !instance.can_exchange_groups(
+                                &counts,
+                                &leaving_groups,
+                                &entering_groups,
+                            )
+                        {
+                            continue;
+                        }
+                        let delta = DoubleSwap::get_delta(
+                            &solution,
+                            (first_index, second_index),
+                            (first_point, second_point),
+                        );
+                        if delta > best_delta {
+                            best_delta = delta;
+                            best_move =
+                                Some((first_index, second_index, first_point, second_point));
+                        }
+                    }
+                }
+            }
+        }
+        match best_move {
+            Some((first_index, second_index, first_point, second_point)) => {
+                let (first_point, second_point) = ((*first_point).clone(), (*second_point).clone());
+                // The second index is greater, so removing it first keeps the first one valid
+                let solution = get_solution_after_swap(solution, second_index, second_point);
+                get_solution_after_swap(solution, first_index, first_point)
+            }
+            None => solution,
+        }
+    }
+}
+
+impl Default for DoubleSwap {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DoubleSwap {
+    pub fn new() -> Self {
+        DoubleSwap {}
+    }
+
+    /// Gets how much the z of a solution would change if the points at the
+    /// specified indexes were replaced with the other two points
+    fn get_delta(
+        solution: &ProblemSolution,
+        (first_index, second_index): (usize, usize),
+        (first_point, second_point): (&Point, &Point),
+    ) -> f64 {
+        let first_leaving_point = &solution.points[first_index];
+        let second_leaving_point = &solution.points[second_index];
+        let delta = solution
+            .points
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| *index != first_index && *index != second_index)
+            .map(|(_, point)| {
+                point.distance_to(first_point) + point.distance_to(second_point)
+                    - point.distance_to(first_leaving_point)
+                    - point.distance_to(second_leaving_point)
+            })
+            .sum::<f64>();
+        delta + first_point.distance_to(second_point)
+            - first_leaving_point.distance_to(second_leaving_point)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn delta() {
+        let solution = ProblemSolution {
+            points: vec![
+                Point::new(vec![0.0, 0.0]),
+                Point::new(vec![0.0, 2.0]),
+                Point::new(vec![1.0, 0.0]),
+                Point::new(vec![1.0, 1.0]),
+            ],
+        };
+        let first_point = Point::new(vec![4.0, 0.0]);
+        let second_point = Point::new(vec![-3.0, 5.0]);
+        let delta = DoubleSwap::get_delta(&solution, (1, 3), (&first_point, &second_point));
+        let new_solution = get_solution_after_swap(solution.clone(), 3, second_point);
+        let new_solution = get_solution_after_swap(new_solution, 1, first_point);
+        assert!((new_solution.get_z() - solution.get_z() - delta).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,62 @@
+use super::*;
+
+/// A local search that performs chains of drop-add moves. Each move drops a point
+/// of the solution and adds a point outside it, choosing the best swap even if it
+/// makes the solution worse. The points moved in a chain can't be moved again in
+/// the same chain. The result is the best solution found along the chain, so it
+/// can cross valleys that a single [Swap](super::Swap) can't
+pub struct DropAddChain {
+    length: usize,
+}
+
+impl LocalSearch for DropAddChain {
+    fn perform_search(
+        &self,
+        instance: &ProblemInstance,
+        solution: ProblemSolution,
+    ) -> ProblemSolution {
+        let mut best_solution = solution.clone();
+        let mut best_z = solution.get_z();
+        let mut actual_solution = solution;
+        let mut actual_z = best_z;
+        let mut moved_points = Vec::with_capacity(self.length * 2);
+        for _ in 0..self.length {
+            let best_swap = get_possible_swaps(instance, &actual_solution)
+                .filter(|(index, point)| {
+                    !moved_points.contains(&actual_solution.points[*index])
+                        && !moved_points.contains(*point)
+                })
+                .map(|(index, point)| {
+                    (index, point, get_swap_delta(&actual_solution, index, point))
+                })
+                .reduce(|max, actual| {
+                    if actual.2 > max.2 {
+                        return actual;
+                    }
+                    max
+                })
+                .map(|(index, point, delta)| (index, point.clone(), delta));
+            let (index, point, delta) = match best_swap {
+                Some(swap) => swap,
+                None => break,
+            };
+            moved_points.push(actual_solution.points[index].clone());
+            moved_points.push(point.clone());
+            actual_solution = get_solution_after_swap(actual_solution, index, point);
+            actual_z += delta;
+            if actual_z > best_z {
+                best_z = actual_z;
+                best_solution = actual_solution.clone();
+            }
+        }
+        best_solution
+    }
+}
+
+impl DropAddChain {
+    /// Creates a new search whose chains have at most the specified number of moves
+    pub fn new(length: usize) -> Self {
+        assert!(length > 0);
+        DropAddChain { length }
+    }
+}
@@ -0,0 +1,34 @@
+use super::*;
+
+/// A local search that consists on doing swaps between points that are in the solution
+/// and points that aren't. Unlike [Swap](super::Swap) it doesn't evaluate the whole
+/// environment, it performs the first swap that improves the solution
+pub struct FirstImprovementSwap {}
+
+impl LocalSearch for FirstImprovementSwap {
+    fn perform_search(
+        &self,
+        instance: &ProblemInstance,
+        solution: ProblemSolution,
+    ) -> ProblemSolution {
+        let improving_swap = get_possible_swaps(instance, &solution)
+            .find(|(index, point)| get_swap_delta(&solution, *index, point) > 0.0)
+            .map(|(index, point)| (index, point.clone()));
+        match improving_swap {
+            Some((index, point)) => get_solution_after_swap(solution, index, point),
+            None => solution,
+        }
+    }
+}
+
+impl Default for FirstImprovementSwap {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FirstImprovementSwap {
+    pub fn new() -> Self {
+        FirstImprovementSwap {}
+    }
+}
@@ -1,12 +1,32 @@
-use super::{ProblemInstance, ProblemSolution};
+use super::{Point, ProblemInstance, ProblemSolution};
+use rand::{seq::SliceRandom, Rng};
+use std::collections::BTreeMap;
+use std::rc::Rc;
 
 mod swap;
 pub use swap::Swap;
+mod first_improvement_swap;
+pub use first_improvement_swap::FirstImprovementSwap;
+mod random_first_improvement_swap;
+pub use random_first_improvement_swap::RandomFirstImprovementSwap;
+mod sampled_swap;
+pub use sampled_swap::SampledSwap;
+mod double_swap;
+pub use double_swap::DoubleSwap;
+mod drop_add_chain;
+pub use drop_add_chain::DropAddChain;
+mod critical_swap;
+pub use critical_swap::CriticalSwap;
+mod vnd;
+pub use vnd::VND;
+
 /// A trait that specifies how a local search should behave. A local search
 /// should search for better solutions inside an specific environment and
 /// only stop searching when it can't find a better one.
 pub trait LocalSearch {
-    /// Performs a local search that stops when there isn't a better solution
+    /// Performs a local search that stops when there isn't a better solution. If the
+    /// instance has a capacity, the best point that fits is added each time the
+    /// environment has no better solution, so the solution can grow
     fn improve(
         &self,
         instance: &ProblemInstance,
@@ -14,10 +34,11 @@ pub trait LocalSearch {
     ) -> ProblemSolution {
         loop {
             let another_solution = self.perform_search(instance, solution.clone());
-            if another_solution.get_z() <= solution.get_z() {
+            if another_solution.get_z() > solution.get_z() {
+                solution = another_solution;
+            } else if !add_best_fitting_point(instance, &mut solution) {
                 return solution;
             }
-            solution = another_solution;
         }
     }
 
@@ -29,3 +50,329 @@ pub trait LocalSearch {
         solution: ProblemSolution,
     ) -> ProblemSolution;
 }
+
+/// Gets the solution that results of replacing the point at the specified index
+/// with another point
+fn get_solution_after_swap(
+    mut solution: ProblemSolution,
+    index: usize,
+    point_to_swap: Point,
+) -> ProblemSolution {
+    solution.points.remove(index);
+    solution.points.push(point_to_swap);
+    solution
+}
+
+/// Gets how much the z of a solution would change if the point at the specified
+/// index was replaced with another point. It only needs to take into account the
+/// distances to the rest of the points of the solution, so it is much faster than
+/// calculating the z of the new solution
+pub(super) fn get_swap_delta(
+    solution: &ProblemSolution,
+    index: usize,
+    point_to_swap: &Point,
+) -> f64 {
+    let leaving_point = &solution.points[index];
+    solution
+        .points
+        .iter()
+        .enumerate()
+        .filter(|(other_index, _)| *other_index != index)
+        .map(|(_, point)| point.distance_to(point_to_swap) - point.distance_to(leaving_point))
+        .sum()
+}
+
+/// Gets the contribution of a point to the z of a solution, which is the sum of
+/// the distances to the points of the solution
+fn get_contribution(solution: &ProblemSolution, point: &Point) -> f64 {
+    solution
+        .points
+        .iter()
+        .map(|other_point| other_point.distance_to(point))
+        .sum()
+}
+
+/// Adds to the solution the point outside it with the greatest contribution among
+/// the ones that fit and whose group can take it, and gets whether a point was
+/// added. Only a capacity lets a solution grow, since without it the solutions
+/// always have the number of points of the solver
+pub(super) fn add_best_fitting_point(
+    instance: &ProblemInstance,
+    solution: &mut ProblemSolution,
+) -> bool {
+    if instance.get_capacity().is_none() {
+        return false;
+    }
+    let weight = solution.get_weight();
+    let counts = instance.get_group_counts(&solution.points);
+    let best_point = get_points_outside_solution(instance, solution)
+        .filter(|point| {
+            instance.fits(weight + point.weight)
+                && instance.can_exchange_groups(&counts, &[], &[point.group])
+        })
+        .map(|point| (point, get_contribution(solution, point)))
+        .reduce(|acc, value| {
+            if value.1 > acc.1 {
+                return value;
+            }
+            acc
+        })
+        .map(|(point, _)| point.clone());
+    match best_point {
+        Some(point) => {
+            solution.points.push(point);
+            true
+        }
+        None => false,
+    }
+}
+
+/// Gets the points of the instance that aren't in the solution and can enter it,
+/// which are the ones that aren't forbidden
+fn get_points_outside_solution<'a>(
+    instance: &'a ProblemInstance,
+    solution: &'a ProblemSolution,
+) -> impl Iterator<Item = &'a Point> + 'a {
+    instance
+        .points
+        .iter()
+        .enumerate()
+        .filter(move |(index, point)| {
+            !instance.is_forbidden_index(*index) && !solution.points.contains(point)
+        })
+        .map(|(_, point)| point)
+}
+
+/// Gets the indexes of the points of the solution that can leave it, which are
+/// the ones that aren't fixed
+fn get_swappable_indexes<'a>(
+    instance: &'a ProblemInstance,
+    solution: &'a ProblemSolution,
+) -> impl Iterator<Item = usize> + 'a {
+    (0..solution.points.len()).filter(move |index| !instance.is_fixed(&solution.points[*index]))
+}
+
+/// Gets all the possible swaps between a point of the solution, represented by
+/// its index, and a point outside the solution. If the instance has a capacity,
+/// the swaps whose result doesn't fit are left out, and so are the ones that take
+/// a group out of its limits
+pub(super) fn get_possible_swaps<'a>(
+    instance: &'a ProblemInstance,
+    solution: &'a ProblemSolution,
+) -> impl Iterator<Item = (usize, &'a Point)> + 'a {
+    let weight = solution.get_weight();
+    let counts = Rc::new(instance.get_group_counts(&solution.points));
+    get_swappable_indexes(instance, solution).flat_map(move |index| {
+        let leaving_point = &solution.points[index];
+        let remaining_weight = weight - leaving_point.weight;
+        let counts = Rc::clone(&counts);
+        get_points_outside_solution(instance, solution)
+            .filter(move |point| {
+                instance.fits(remaining_weight + point.weight)
+                    && instance.can_exchange_groups(&counts, &[leaving_point.group], &[point.group])
+            })
+            .map(move |point| (index, point))
+    })
+}
+
+/// Gets the same swaps as [get_possible_swaps] in a random order. The indexes of
+/// the solution and the points outside it are shuffled, and the pairs are formed
+/// lazily from both lists, so a search that stops at the first good swap doesn't
+/// generate the whole environment
+pub(super) fn get_shuffled_possible_swaps<'a, R: Rng>(
+    instance: &'a ProblemInstance,
+    solution: &'a ProblemSolution,
+    rng: &mut R,
+) -> impl Iterator<Item = (usize, &'a Point)> + 'a {
+    let weight = solution.get_weight();
+    let counts = Rc::new(instance.get_group_counts(&solution.points));
+    let mut indexes = get_swappable_indexes(instance, solution).collect::<Vec<usize>>();
+    indexes.shuffle(rng);
+    let mut outside_points = get_points_outside_solution(instance, solution).collect::<Vec<_>>();
+    outside_points.shuffle(rng);
+    let outside_points = Rc::new(outside_points);
+    indexes.into_iter().flat_map(move |index| {
+        let leaving_point = &solution.points[index];
+        let remaining_weight = weight - leaving_point.weight;
+        let counts = Rc::clone(&counts);
+        let outside_points = Rc::clone(&outside_points);
+        (0..outside_points.len())
+            .map(move |position| outside_points[position])
+            .filter(move |point| {
+                instance.fits(remaining_weight + point.weight)
+                    && instance.can_exchange_groups(&counts, &[leaving_point.group], &[point.group])
+            })
+            .map(move |point| (index, point))
+    })
+}
+
+/// Whether the point at the specified index of the solution can be replaced with
+/// the given point, following the same rules as [get_possible_swaps]. The weight
+/// and the counts per group of the solution are passed so they aren't calculated
+/// for every pair
+pub(super) fn is_possible_swap(
+    instance: &ProblemInstance,
+    solution: &ProblemSolution,
+    weight: f64,
+    counts: &BTreeMap<usize, usize>,
+    index: usize,
+    point: &Point,
+) -> bool {
+    let leaving_point = &solution.points[index];
+    !instance.is_fixed(leaving_point)
+        && !instance.is_forbidden(point)
+        && !solution.points.contains(point)
+        && instance.fits(weight - leaving_point.weight + point.weight)
+        && instance.can_exchange_groups(counts, &[leaving_point.group], &[point.group])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    #[test]
+    fn swap_delta() {
+        let solution = ProblemSolution {
+            points: vec![
+                Point::new(vec![0.0, 0.0]),
+                Point::new(vec![0.0, 2.0]),
+                Point::new(vec![1.0, 0.0]),
+            ],
+        };
+        let point = Point::new(vec![4.0, 0.0]);
+        let delta = get_swap_delta(&solution, 2, &point);
+        let new_solution = get_solution_after_swap(solution.clone(), 2, point);
+        assert!((new_solution.get_z() - solution.get_z() - delta).abs() < 1e-9);
+    }
+    #[test]
+    fn first_improvement_reaches_local_optimum() {
+        let instance = ProblemInstance::from_file("problem_instances/max_div_30_2.txt").unwrap();
+        let solution = ProblemSolution {
+            points: instance.points[..5].to_vec(),
+        };
+        let solution = FirstImprovementSwap::new().improve(&instance, solution);
+        let best_neighbour = Swap::new().perform_search(&instance, solution.clone());
+        assert!(best_neighbour.get_z() <= solution.get_z() + 1e-9);
+    }
+    #[test]
+    fn sampled_swap_only_performs_possible_swaps() {
+        let instance = ProblemInstance::from_file("problem_instances/max_div_20_2_grouped.txt")
+            .unwrap()
+            .with_fixed_points(vec![0])
+            .with_forbidden_points(vec![5, 6])
+            .with_group_limits(1, 1, 1);
+        let solution = ProblemSolution {
+            points: instance.points[..4].to_vec(),
+        };
+        let improved_solution = SampledSwap::new(200)
+            .with_seed(1)
+            .improve(&instance, solution.clone());
+        assert!(improved_solution.get_z() > solution.get_z());
+        assert!(improved_solution.is_feasible(&instance, 4));
+    }
+    #[test]
+    fn vnd_reaches_local_optimum_of_every_neighborhood() {
+        let instance = ProblemInstance::from_file("problem_instances/max_div_20_2.txt").unwrap();
+        let solution = ProblemSolution {
+            points: instance.points[..4].to_vec(),
+        };
+        let vnd = VND::new()
+            .with_neighborhood(CriticalSwap::new())
+            .with_neighborhood(Swap::new())
+            .with_neighborhood(DoubleSwap::new());
+        let solution = vnd.improve(&instance, solution);
+        for neighbour in [
+            Swap::new().perform_search(&instance, solution.clone()),
+            DoubleSwap::new().perform_search(&instance, solution.clone()),
+        ] {
+            assert!(neighbour.get_z() <= solution.get_z() + 1e-9);
+        }
+    }
+    #[test]
+    fn swaps_keep_fixed_and_forbidden_points() {
+        let instance = ProblemInstance::from_file("problem_instances/max_div_20_2.txt")
+            .unwrap()
+            .with_fixed_points(vec![0])
+            .with_forbidden_points(vec![4, 5, 6]);
+        let solution = ProblemSolution {
+            points: instance.points[..4].to_vec(),
+        };
+        let solution = Swap::new().improve(&instance, solution);
+        assert!(solution.points.contains(&instance.points[0]));
+        assert!(get_possible_swaps(&instance, &solution)
+            .all(|(index, point)| !instance.is_fixed(&solution.points[index])
+                && !instance.is_forbidden(point)));
+    }
+    #[test]
+    fn shuffled_swaps_are_the_possible_swaps() {
+        let instance = ProblemInstance::from_file("problem_instances/max_div_20_2_balanced.txt")
+            .unwrap()
+            .with_fixed_points(vec![0])
+            .with_forbidden_points(vec![5]);
+        let solution = ProblemSolution {
+            points: instance.points[..4].to_vec(),
+        };
+        let mut rng = rand::rngs::StdRng::seed_from_u64(1);
+        let mut shuffled_swaps = get_shuffled_possible_swaps(&instance, &solution, &mut rng)
+            .collect::<Vec<(usize, &Point)>>();
+        let possible_swaps = get_possible_swaps(&instance, &solution).collect::<Vec<_>>();
+        assert_eq!(shuffled_swaps.len(), possible_swaps.len());
+        shuffled_swaps.retain(|swap| !possible_swaps.contains(swap));
+        assert!(shuffled_swaps.is_empty());
+    }
+    #[test]
+    fn swaps_respect_capacity() {
+        let instance = ProblemInstance::from_file("problem_instances/max_div_15_2_weighted.txt")
+            .unwrap()
+            .with_capacity(8.0);
+        // The first and the sixth points are the lightest ones
+        let solution = ProblemSolution {
+            points: vec![instance.points[0].clone(), instance.points[5].clone()],
+        };
+        for local_search in [
+            Box::new(Swap::new()) as Box<dyn LocalSearch>,
+            Box::new(CriticalSwap::new()),
+            Box::new(DoubleSwap::new()),
+        ] {
+            let mut solution = local_search.improve(&instance, solution.clone());
+            assert!(solution.is_feasible(&instance, 2));
+            // The capacity replaces the number of points, so no point fits anymore
+            assert!(!add_best_fitting_point(&instance, &mut solution));
+        }
+    }
+    #[test]
+    fn searches_from_an_empty_solution() {
+        let instance = ProblemInstance::from_file("problem_instances/max_div_15_2_weighted.txt")
+            .unwrap()
+            .with_capacity(0.5);
+        for local_search in [
+            Box::new(Swap::new()) as Box<dyn LocalSearch>,
+            Box::new(FirstImprovementSwap::new()),
+            Box::new(RandomFirstImprovementSwap::new().with_seed(1)),
+            Box::new(SampledSwap::new(20).with_seed(1)),
+            Box::new(CriticalSwap::new()),
+            Box::new(DoubleSwap::new()),
+        ] {
+            let solution = local_search.improve(&instance, ProblemSolution { points: vec![] });
+            assert!(solution.points.is_empty());
+        }
+    }
+    #[test]
+    fn swaps_respect_group_limits() {
+        let instance =
+            ProblemInstance::from_file("problem_instances/max_div_20_2_balanced.txt").unwrap();
+        // The groups of the points cycle from 0 to 2
+        let solution = ProblemSolution {
+            points: instance.points[..4].to_vec(),
+        };
+        assert!(solution.is_feasible(&instance, 4));
+        for local_search in [
+            Box::new(Swap::new()) as Box<dyn LocalSearch>,
+            Box::new(CriticalSwap::new()),
+            Box::new(DoubleSwap::new()),
+        ] {
+            let solution = local_search.improve(&instance, solution.clone());
+            assert!(solution.is_feasible(&instance, 4));
+        }
+    }
+}
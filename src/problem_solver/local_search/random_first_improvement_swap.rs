@@ -0,0 +1,48 @@
+use super::*;
+use rand::{rngs::StdRng, SeedableRng};
+use std::cell::RefCell;
+
+/// A local search that consists on doing swaps between points that are in the solution
+/// and points that aren't. It evaluates the swaps in a random order and performs the
+/// first one that improves the solution, so it doesn't favour the points that come
+/// first in the instance like [FirstImprovementSwap](super::FirstImprovementSwap)
+pub struct RandomFirstImprovementSwap {
+    rng: RefCell<StdRng>,
+}
+
+impl LocalSearch for RandomFirstImprovementSwap {
+    fn perform_search(
+        &self,
+        instance: &ProblemInstance,
+        solution: ProblemSolution,
+    ) -> ProblemSolution {
+        let improving_swap =
+            get_shuffled_possible_swaps(instance, &solution, &mut *self.rng.borrow_mut())
+                .find(|(index, point)| get_swap_delta(&solution, *index, point) > 0.0)
+                .map(|(index, point)| (index, point.clone()));
+        match improving_swap {
+            Some((index, point)) => get_solution_after_swap(solution, index, point),
+            None => solution,
+        }
+    }
+}
+
+impl Default for RandomFirstImprovementSwap {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RandomFirstImprovementSwap {
+    pub fn new() -> Self {
+        RandomFirstImprovementSwap {
+            rng: RefCell::new(StdRng::from_entropy()),
+        }
+    }
+
+    /// Seeds the random number generator, so the searches are reproducible
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.rng = RefCell::new(StdRng::seed_from_u64(seed));
+        self
+    }
+}
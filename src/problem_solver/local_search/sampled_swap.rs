@@ -0,0 +1,70 @@
+use super::*;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use std::cell::RefCell;
+
+/// A local search that consists on doing swaps between points that are in the solution
+/// and points that aren't. Instead of evaluating the whole environment it evaluates
+/// a random sample of the swaps and performs the best one if it improves the solution.
+/// The sample is drawn from the pairs of a point of the solution and a point of the
+/// instance, so its cost doesn't depend on the size of the environment. The pairs that
+/// aren't a possible swap are discarded. The search stops when a sample doesn't have
+/// any improving swap
+pub struct SampledSwap {
+    sample_size: usize,
+    rng: RefCell<StdRng>,
+}
+
+impl LocalSearch for SampledSwap {
+    fn perform_search(
+        &self,
+        instance: &ProblemInstance,
+        solution: ProblemSolution,
+    ) -> ProblemSolution {
+        if solution.points.is_empty() {
+            return solution;
+        }
+        let weight = solution.get_weight();
+        let counts = instance.get_group_counts(&solution.points);
+        let mut rng = self.rng.borrow_mut();
+        let best_swap = (0..self.sample_size)
+            .map(|_| {
+                (
+                    rng.gen_range(0..solution.points.len()),
+                    &instance.points[rng.gen_range(0..instance.points.len())],
+                )
+            })
+            .filter(|(index, point)| {
+                is_possible_swap(instance, &solution, weight, &counts, *index, point)
+            })
+            .map(|(index, point)| (index, point, get_swap_delta(&solution, index, point)))
+            .filter(|(_, _, delta)| *delta > 0.0)
+            .reduce(|best, actual| {
+                if actual.2 > best.2 {
+                    return actual;
+                }
+                best
+            })
+            .map(|(index, point, _)| (index, point.clone()));
+        match best_swap {
+            Some((index, point)) => get_solution_after_swap(solution, index, point),
+            None => solution,
+        }
+    }
+}
+
+impl SampledSwap {
+    /// Creates a new search that evaluates the specified number of swaps in each step
+    pub fn new(sample_size: usize) -> Self {
+        assert!(sample_size > 0);
+        SampledSwap {
+            sample_size,
+            rng: RefCell::new(StdRng::from_entropy()),
+        }
+    }
+
+    /// Seeds the random number generator, so the searches are reproducible
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.rng = RefCell::new(StdRng::seed_from_u64(seed));
+        self
+    }
+}
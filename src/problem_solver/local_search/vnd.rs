@@ -0,0 +1,47 @@
+use super::*;
+
+/// A variable neighborhood descent. It uses an ordered list of local searches,
+/// each one exploring a different environment. In each step it searches in the
+/// environments in order until one of them finds a better solution, and then it
+/// starts again from the first one. It stops when the solution is a local optimum
+/// for all the environments
+pub struct VND {
+    neighborhoods: Vec<Box<dyn LocalSearch + Send>>,
+}
+
+impl LocalSearch for VND {
+    fn perform_search(
+        &self,
+        instance: &ProblemInstance,
+        solution: ProblemSolution,
+    ) -> ProblemSolution {
+        let z = solution.get_z();
+        self.neighborhoods
+            .iter()
+            .map(|neighborhood| neighborhood.perform_search(instance, solution.clone()))
+            .find(|new_solution| new_solution.get_z() > z)
+            .unwrap_or(solution)
+    }
+}
+
+impl Default for VND {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl VND {
+    /// Creates a new VND without environments. They need to be added with
+    /// [with_neighborhood](VND::with_neighborhood)
+    pub fn new() -> Self {
+        VND {
+            neighborhoods: Vec::new(),
+        }
+    }
+
+    /// Adds a local search after the ones that were already added
+    pub fn with_neighborhood<L: LocalSearch + Send + 'static>(mut self, neighborhood: L) -> Self {
+        self.neighborhoods.push(Box::new(neighborhood));
+        self
+    }
+}
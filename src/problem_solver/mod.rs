@@ -6,15 +6,71 @@ pub use greedy_solver::GreedySolver;
 mod randomized_greedy_solver;
 pub use randomized_greedy_solver::RandomizedGreedySolver;
 mod tabu_search;
-pub use tabu_search::TabuSearch;
+pub use tabu_search::{RestartStrategy, TabuSearch, TabuSearchState};
 mod branch_and_bound;
-pub use branch_and_bound::{BranchAndBound, DeepBranchAndBound};
+pub use branch_and_bound::{
+    BeamSearch, BranchAndBound, BranchingOrder, DeepBranchAndBound, DegradationMode,
+    OptimalityCertificate,
+};
 mod grasp;
 pub use grasp::GRASP;
+mod checkpoint;
 pub mod local_search;
+mod vns;
+pub use vns::VNS;
+mod ils;
+mod perturbation;
+pub use ils::ILS;
+mod acceptance_criterion;
+pub use acceptance_criterion::AcceptanceCriterion;
+mod simulated_annealing;
+pub use simulated_annealing::SimulatedAnnealing;
+mod cooling_schedule;
+pub use checkpoint::CheckpointError;
+pub use cooling_schedule::CoolingSchedule;
+mod genetic_algorithm;
+pub use genetic_algorithm::GeneticAlgorithm;
+mod crossover;
+pub use crossover::Crossover;
+mod elite_pool;
+pub use elite_pool::ElitePool;
+mod path_relinking;
+pub use path_relinking::PathRelinking;
+mod scatter_search;
+pub use scatter_search::ScatterSearch;
+mod reactive_grasp;
+pub use reactive_grasp::ReactiveGRASP;
+mod restricted_candidate_list;
+pub use restricted_candidate_list::{RestrictedCandidateList, SelectionBias};
+mod greedy_criterion;
+pub use greedy_criterion::GreedyCriterion;
+mod constructive_solver;
+pub use constructive_solver::ConstructiveSolver;
+mod scoring_function;
+pub use scoring_function::ScoringFunction;
+mod selection_rule;
+pub use selection_rule::SelectionRule;
+mod pipeline;
+pub use pipeline::Pipeline;
+mod incumbent;
+pub use incumbent::Incumbent;
+mod portfolio;
+pub use portfolio::Portfolio;
+mod grouping_solution;
+pub use grouping_solution::GroupingSolution;
+mod grouping_solver;
+pub use grouping_solver::GroupingSolver;
 
 /// A trait for an algorithm that is able to solve an instance of the problem
 pub trait ProblemSolver {
     /// Solves an instance of the problem
     fn solve(&mut self, instance: &ProblemInstance) -> ProblemSolution;
 }
+
+/// Allows lending a solver to an algorithm that takes ownership of its solvers,
+/// like the initial solvers of [BranchAndBound](BranchAndBound)
+impl<S: ProblemSolver + ?Sized> ProblemSolver for &mut S {
+    fn solve(&mut self, instance: &ProblemInstance) -> ProblemSolution {
+        (**self).solve(instance)
+    }
+}
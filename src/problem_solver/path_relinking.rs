@@ -0,0 +1,115 @@
+use super::local_search::{get_swap_delta, is_possible_swap};
+use super::{ProblemInstance, ProblemSolution};
+
+/// The strategy used to explore the path between two solutions. In each step of
+/// the path the best swap that replaces a point that isn't in the guiding solution
+/// with one that is in it is performed, and the best solution of the path is kept.
+/// Only the swaps the local searches can perform are considered, so a fixed point
+/// is never swapped out, and the path ends early if no swap is left
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum PathRelinking {
+    /// The path goes from the worse solution to the better one
+    Forward,
+    /// The path goes from the better solution to the worse one
+    Backward,
+    /// Both solutions move towards each other alternately until they meet
+    #[default]
+    Mixed,
+}
+
+impl PathRelinking {
+    /// Explores the path between two solutions and gets the best solution found in
+    /// it. If they have a different number of points, which can only happen with a
+    /// capacity, the path ends before reaching the guiding solution
+    pub fn relink(
+        &self,
+        instance: &ProblemInstance,
+        solution1: &ProblemSolution,
+        solution2: &ProblemSolution,
+    ) -> ProblemSolution {
+        let (worse_solution, better_solution) = if solution1.get_z() > solution2.get_z() {
+            (solution2, solution1)
+        } else {
+            (solution1, solution2)
+        };
+        let (mut actual_solution, mut guiding_solution) = match self {
+            PathRelinking::Backward => (better_solution.clone(), worse_solution.clone()),
+            _ => (worse_solution.clone(), better_solution.clone()),
+        };
+        let mut actual_z = actual_solution.get_z();
+        let mut guiding_z = guiding_solution.get_z();
+        let mut best_solution = better_solution.clone();
+        let mut best_z = best_solution.get_z();
+        while let Some(delta) =
+            PathRelinking::perform_step(instance, &mut actual_solution, &guiding_solution)
+        {
+            actual_z += delta;
+            if actual_z > best_z {
+                best_solution = actual_solution.clone();
+                best_z = actual_z;
+            }
+            if *self == PathRelinking::Mixed {
+                std::mem::swap(&mut actual_solution, &mut guiding_solution);
+                std::mem::swap(&mut actual_z, &mut guiding_z);
+            }
+        }
+        best_solution
+    }
+
+    /// Performs the best swap that moves the solution towards the guiding solution
+    /// and gets how much its z changed. If both solutions are already the same
+    /// nothing is done
+    fn perform_step(
+        instance: &ProblemInstance,
+        solution: &mut ProblemSolution,
+        guiding_solution: &ProblemSolution,
+    ) -> Option<f64> {
+        let weight = solution.get_weight();
+        let counts = &instance.get_group_counts(&solution.points);
+        let (index, point, delta) = (0..solution.points.len())
+            .filter(|index| !guiding_solution.points.contains(&solution.points[*index]))
+            .flat_map(|index| {
+                let solution = &*solution;
+                guiding_solution
+                    .points
+                    .iter()
+                    .filter(move |point| {
+                        is_possible_swap(instance, solution, weight, counts, index, point)
+                    })
+                    .map(move |point| (index, point, get_swap_delta(solution, index, point)))
+            })
+            .reduce(|acc, value| {
+                if value.2 > acc.2 {
+                    return value;
+                }
+                acc
+            })?;
+        solution.points[index] = point.clone();
+        Some(delta)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn path_is_not_worse_than_the_best_end() {
+        let instance = ProblemInstance::from_file("problem_instances/max_div_20_2.txt").unwrap();
+        let solution1 = ProblemSolution {
+            points: instance.points[..5].to_vec(),
+        };
+        let solution2 = ProblemSolution {
+            points: instance.points[15..].to_vec(),
+        };
+        let best_z = solution1.get_z().max(solution2.get_z());
+        for path_relinking in [
+            PathRelinking::Forward,
+            PathRelinking::Backward,
+            PathRelinking::Mixed,
+        ] {
+            let solution = path_relinking.relink(&instance, &solution1, &solution2);
+            assert_eq!(solution.get_points().len(), 5);
+            assert!(solution.get_z() >= best_z - 1e-9);
+        }
+    }
+}
@@ -0,0 +1,27 @@
+use super::local_search::get_shuffled_possible_swaps;
+use super::{ProblemInstance, ProblemSolution};
+use rand::Rng;
+
+/// Perturbs a solution by performing the specified number of random swaps between
+/// a point in the solution and a point outside the solution. Only the swaps the
+/// local searches can perform are chosen, so the fixed points aren't swapped out
+/// and the forbidden ones aren't swapped in. A point that enters the solution can
+/// be swapped out again by a later swap
+pub(crate) fn perform_random_swaps<R: Rng>(
+    instance: &ProblemInstance,
+    mut solution: ProblemSolution,
+    swaps: usize,
+    rng: &mut R,
+) -> ProblemSolution {
+    for _ in 0..swaps {
+        let swap = get_shuffled_possible_swaps(instance, &solution, rng)
+            .next()
+            .map(|(index, point)| (index, point.clone()));
+        let (index, point) = match swap {
+            Some(swap) => swap,
+            None => return solution,
+        };
+        solution.points[index] = point;
+    }
+    solution
+}
@@ -0,0 +1,156 @@
+use super::{
+    local_search::LocalSearch, BranchAndBound, OptimalityCertificate, ProblemInstance,
+    ProblemSolution, ProblemSolver,
+};
+use std::time::Duration;
+
+/// A stage of a pipeline that gets the best solution found so far
+enum Stage<'a> {
+    Improve(Box<dyn LocalSearch + Send + 'a>),
+    Solve(Box<dyn FnMut(ProblemSolution) -> Box<dyn ProblemSolver + Send + 'a> + Send + 'a>),
+    Prove(Option<Duration>),
+}
+
+/// A solver made of several stages. The first one constructs a solution and each of
+/// the following ones starts from the best solution found so far, which can be
+/// improved with a [local search](super::local_search::LocalSearch), passed to a
+/// solver created for it, like a [tabu search](super::TabuSearch) with that initial
+/// solution, or used as the initial lower bound of a [branch and bound](super::BranchAndBound)
+/// that proves its optimality. The result of a stage is only kept if it is better
+pub struct Pipeline<'a> {
+    number_of_points: usize,
+    constructive_solver: Box<dyn ProblemSolver + Send + 'a>,
+    stages: Vec<Stage<'a>>,
+    stage_z: Vec<f64>,
+    certificate: Option<OptimalityCertificate>,
+}
+
+impl<'a> ProblemSolver for Pipeline<'a> {
+    fn solve(&mut self, instance: &ProblemInstance) -> ProblemSolution {
+        let mut best_solution = self.constructive_solver.solve(instance);
+        self.stage_z = vec![best_solution.get_z()];
+        self.certificate = None;
+        for stage in self.stages.iter_mut() {
+            let solution = match stage {
+                Stage::Improve(local_search) => {
+                    local_search.improve(instance, best_solution.clone())
+                }
+                Stage::Solve(create_solver) => create_solver(best_solution.clone()).solve(instance),
+                Stage::Prove(time_limit) => {
+                    let mut solver = BranchAndBound::new(self.number_of_points)
+                        .with_initial_solution(best_solution.clone());
+                    if let Some(time_limit) = time_limit {
+                        solver = solver.with_time_limit(*time_limit);
+                    }
+                    let solution = solver.solve(instance);
+                    self.certificate = solver.get_certificate();
+                    solution
+                }
+            };
+            if solution.get_z() > best_solution.get_z() {
+                best_solution = solution;
+            }
+            self.stage_z.push(best_solution.get_z());
+        }
+        best_solution
+    }
+}
+
+impl<'a> Pipeline<'a> {
+    /// Creates a new pipeline whose first stage is the given solver. The number of
+    /// points the solutions will have is used by the branch and bound stages
+    pub fn new<S: ProblemSolver + Send + 'a>(
+        number_of_points: usize,
+        constructive_solver: S,
+    ) -> Self {
+        assert!(number_of_points > 0);
+        Pipeline {
+            number_of_points,
+            constructive_solver: Box::new(constructive_solver),
+            stages: Vec::new(),
+            stage_z: Vec::new(),
+            certificate: None,
+        }
+    }
+
+    /// Adds a stage that improves the solution with the given local search
+    pub fn then_improve<L: LocalSearch + Send + 'a>(mut self, local_search: L) -> Self {
+        self.stages.push(Stage::Improve(Box::new(local_search)));
+        self
+    }
+
+    /// Adds a stage that runs the solver created by the given function from the
+    /// best solution found so far
+    pub fn then<S, F>(mut self, mut create_solver: F) -> Self
+    where
+        S: ProblemSolver + Send + 'a,
+        F: FnMut(ProblemSolution) -> S + Send + 'a,
+    {
+        self.stages.push(Stage::Solve(Box::new(move |solution| {
+            Box::new(create_solver(solution))
+        })));
+        self
+    }
+
+    /// Adds a stage that runs a branch and bound that uses the best solution found so
+    /// far as its initial lower bound, proving if it is optimal
+    pub fn then_prove(mut self) -> Self {
+        self.stages.push(Stage::Prove(None));
+        self
+    }
+
+    /// Adds a stage like [then_prove](Pipeline::then_prove) whose branch and bound
+    /// stops when the time limit is reached
+    pub fn then_prove_with_time_limit(mut self, time_limit: Duration) -> Self {
+        self.stages.push(Stage::Prove(Some(time_limit)));
+        self
+    }
+
+    /// Gets the z of the best solution found after each stage of the last run,
+    /// starting with the constructive one
+    pub fn get_stage_z(&self) -> &[f64] {
+        &self.stage_z
+    }
+
+    /// Gets the certificate of the last branch and bound stage of the last run, if
+    /// the pipeline has one
+    pub fn get_certificate(&self) -> Option<OptimalityCertificate> {
+        self.certificate
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::problem_solver::{local_search::Swap, GreedySolver, TabuSearch};
+    #[test]
+    fn construct_improve_and_prove() {
+        let instance = ProblemInstance::from_file("problem_instances/max_div_20_2.txt").unwrap();
+        let mut pipeline = Pipeline::new(4, GreedySolver::new(4))
+            .then_improve(Swap::new())
+            .then(|solution| {
+                TabuSearch::new(4, 2, 1, 5)
+                    .with_seed(1)
+                    .with_initial_solution(solution)
+            })
+            .then_prove();
+        let solution = pipeline.solve(&instance);
+        let stage_z = pipeline.get_stage_z();
+        assert_eq!(stage_z.len(), 4);
+        assert!(stage_z.windows(2).all(|z| z[0] <= z[1]));
+        assert!(pipeline.get_certificate().unwrap().is_proven_optimal());
+        assert_eq!(solution.get_z(), stage_z[3]);
+    }
+    #[test]
+    fn prove_with_capacity() {
+        let instance =
+            ProblemInstance::from_file("problem_instances/max_div_15_2_capacity.txt").unwrap();
+        let optimum = BranchAndBound::new(5).solve(&instance);
+        let mut pipeline = Pipeline::new(5, GreedySolver::new(5))
+            .then_improve(Swap::new())
+            .then_prove();
+        let solution = pipeline.solve(&instance);
+        assert!((solution.get_z() - optimum.get_z()).abs() < 1e-9);
+        assert!(pipeline.get_certificate().unwrap().is_proven_optimal());
+    }
+}
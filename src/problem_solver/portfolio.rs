@@ -0,0 +1,179 @@
+use super::{Incumbent, ProblemInstance, ProblemSolution, ProblemSolver};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// How often the portfolio checks if its solvers have finished
+const POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// A function that creates a solver of a portfolio for each run from the incumbent it
+/// has to share
+type CreateSolver<'a> = Box<dyn FnMut(Incumbent) -> Box<dyn ProblemSolver + Send + 'a> + Send + 'a>;
+
+/// A solver that races several solvers in their own threads. They share an
+/// [incumbent](super::Incumbent) with the best solution found so far, so a
+/// [branch and bound](super::BranchAndBound) can use the solutions of the
+/// metaheuristics as its lower bound. When the time budget runs out the incumbent
+/// is stopped and the best solution is returned, so every solver has to stop when
+/// its incumbent is stopped
+pub struct Portfolio<'a> {
+    time_budget: Duration,
+    solvers: Vec<(String, CreateSolver<'a>)>,
+    winner: Option<usize>,
+}
+
+impl<'a> ProblemSolver for Portfolio<'a> {
+    fn solve(&mut self, instance: &ProblemInstance) -> ProblemSolution {
+        assert!(!self.solvers.is_empty());
+        let incumbent = Incumbent::new();
+        let solvers = self
+            .solvers
+            .iter_mut()
+            .enumerate()
+            .map(|(source, (_, create_solver))| create_solver(incumbent.with_source(source)))
+            .collect::<Vec<_>>();
+        let time_budget = self.time_budget;
+        thread::scope(|scope| {
+            let handles = solvers
+                .into_iter()
+                .enumerate()
+                .map(|(source, mut solver)| {
+                    let incumbent = incumbent.with_source(source);
+                    scope.spawn(move || {
+                        let solution = solver.solve(instance);
+                        incumbent.offer_solution(&solution);
+                    })
+                })
+                .collect::<Vec<_>>();
+            let start = Instant::now();
+            while start.elapsed() < time_budget
+                && !handles.iter().all(|handle| handle.is_finished())
+            {
+                thread::sleep(POLL_INTERVAL);
+            }
+            incumbent.stop();
+        });
+        self.winner = incumbent.get_source();
+        // Every solver has offered its solution after finishing
+        incumbent.get_best_solution().unwrap()
+    }
+}
+
+impl<'a> Portfolio<'a> {
+    /// Creates an empty portfolio whose solvers will be stopped once the specified
+    /// amount of time has passed
+    pub fn new(time_budget: Duration) -> Self {
+        Portfolio {
+            time_budget,
+            solvers: Vec::new(),
+            winner: None,
+        }
+    }
+
+    /// Adds a solver with the specified name that is created in each run by the given
+    /// function from the incumbent it has to share, like a
+    /// [branch and bound](super::BranchAndBound) with that incumbent. The solver
+    /// must use the incumbent, since otherwise it can't be stopped
+    pub fn with_incumbent_solver<S, F>(mut self, name: &str, mut create_solver: F) -> Self
+    where
+        S: ProblemSolver + Send + 'a,
+        F: FnMut(Incumbent) -> S + Send + 'a,
+    {
+        self.solvers.push((
+            name.to_string(),
+            Box::new(move |incumbent| Box::new(create_solver(incumbent)) as Box<_>),
+        ));
+        self
+    }
+
+    /// Gets the name of the solver that found the solution of the last run. It will
+    /// be None if the problem hasn't been solved yet
+    pub fn get_winner(&self) -> Option<&str> {
+        self.winner.map(|winner| self.solvers[winner].0.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::problem_solver::{
+        local_search::Swap, BranchAndBound, DeepBranchAndBound, GeneticAlgorithm,
+        SimulatedAnnealing, TabuSearch, GRASP,
+    };
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    #[test]
+    fn portfolio_finds_optimum() {
+        let instance = ProblemInstance::from_file("problem_instances/max_div_20_2.txt").unwrap();
+        let optimal_z = BranchAndBound::new(4).solve(&instance).get_z();
+        let mut portfolio = Portfolio::new(Duration::from_secs(60))
+            .with_incumbent_solver("tabu", |incumbent| {
+                TabuSearch::new(4, 2, 2, 5)
+                    .with_seed(1)
+                    .with_incumbent(incumbent)
+            })
+            .with_incumbent_solver("grasp", |incumbent| {
+                GRASP::new(4, 2, Swap::new(), 5)
+                    .with_seed(1)
+                    .with_incumbent(incumbent)
+            })
+            .with_incumbent_solver("b&b", |incumbent| {
+                DeepBranchAndBound::new(4).with_incumbent(incumbent)
+            });
+        let solution = portfolio.solve(&instance);
+        assert!((solution.get_z() - optimal_z).abs() < 1e-9);
+        assert!(["tabu", "grasp", "b&b"].contains(&portfolio.get_winner().unwrap()));
+    }
+    /// Counts the solvers that return after the incumbent has been stopped
+    struct StopCounter {
+        solver: Box<dyn ProblemSolver + Send>,
+        incumbent: Incumbent,
+        stopped_solvers: Arc<AtomicUsize>,
+    }
+    impl ProblemSolver for StopCounter {
+        fn solve(&mut self, instance: &ProblemInstance) -> ProblemSolution {
+            let solution = self.solver.solve(instance);
+            if self.incumbent.is_stopped() {
+                self.stopped_solvers.fetch_add(1, Ordering::SeqCst);
+            }
+            solution
+        }
+    }
+    #[test]
+    fn portfolio_stops_at_time_budget() {
+        let instance = ProblemInstance::from_file("problem_instances/max_div_30_3.txt").unwrap();
+        let stopped_solvers = Arc::new(AtomicUsize::new(0));
+        let counter = |solver: Box<dyn ProblemSolver + Send>, incumbent| StopCounter {
+            solver,
+            incumbent,
+            stopped_solvers: Arc::clone(&stopped_solvers),
+        };
+        // None of the solvers can finish within the time budget
+        let mut portfolio = Portfolio::new(Duration::from_millis(200))
+            .with_incumbent_solver("tabu", |incumbent: Incumbent| {
+                let solver = TabuSearch::new(10, 3, usize::MAX, 100)
+                    .with_seed(1)
+                    .with_incumbent(incumbent.clone());
+                counter(Box::new(solver), incumbent)
+            })
+            .with_incumbent_solver("annealing", |incumbent: Incumbent| {
+                let solver = SimulatedAnnealing::new(10, 1000, usize::MAX)
+                    .with_seed(1)
+                    .with_incumbent(incumbent.clone());
+                counter(Box::new(solver), incumbent)
+            })
+            .with_incumbent_solver("genetic", |incumbent: Incumbent| {
+                let solver = GeneticAlgorithm::new(10, 10, 1_000_000_000)
+                    .with_seed(1)
+                    .with_incumbent(incumbent.clone());
+                counter(Box::new(solver), incumbent)
+            })
+            .with_incumbent_solver("b&b", |incumbent: Incumbent| {
+                let solver = BranchAndBound::new(10).with_incumbent(incumbent.clone());
+                counter(Box::new(solver), incumbent)
+            });
+        let solution = portfolio.solve(&instance);
+        assert_eq!(stopped_solvers.load(Ordering::SeqCst), 4);
+        assert_eq!(solution.points.len(), 10);
+        assert!(portfolio.get_winner().is_some());
+    }
+}
@@ -1,6 +1,6 @@
 use std::fmt;
 
-use super::Point;
+use super::{Point, ProblemInstance};
 
 /// A struct that represents a solution to the problem. It contains the points
 /// that have been chosen for the solution
@@ -23,6 +23,44 @@ impl ProblemSolution {
         total
     }
 
+    /// Gets the sum of the weights of the points in the solution
+    pub fn get_weight(&self) -> f64 {
+        self.points.iter().map(|point| point.weight).sum()
+    }
+
+    /// Whether the solution respects the fixed and forbidden points, the capacity and
+    /// the group limits of the instance, and has the specified number of points. If the
+    /// instance has a capacity it replaces that number, so only the weight is checked
+    pub fn is_feasible(&self, instance: &ProblemInstance, number_of_points: usize) -> bool {
+        (instance.get_capacity().is_some() || self.points.len() == number_of_points)
+            && instance.fits(self.get_weight())
+            && instance
+                .get_fixed_points()
+                .iter()
+                .all(|index| self.points.contains(&instance.points[*index]))
+            && !self.points.iter().any(|point| instance.is_forbidden(point))
+            && self.respects_group_limits(instance)
+    }
+
+    /// Whether every point of the solution is a different point of the instance
+    pub(super) fn is_from_instance(&self, instance: &ProblemInstance) -> bool {
+        self.points.iter().enumerate().all(|(index, point)| {
+            instance.points.contains(point) && !self.points[..index].contains(point)
+        })
+    }
+
+    /// Whether the number of points of each group is within its limits
+    fn respects_group_limits(&self, instance: &ProblemInstance) -> bool {
+        let counts = instance.get_group_counts(&self.points);
+        instance
+            .group_limits
+            .iter()
+            .all(|(group, (min_points, max_points))| {
+                let count = counts.get(group).copied().unwrap_or(0);
+                *min_points <= count && count <= *max_points
+            })
+    }
+
     /// Allows getting the total completion time of each machine
     pub fn get_points(&self) -> &Vec<Point> {
         &self.points
@@ -53,14 +91,7 @@ mod tests {
     use super::*;
     #[test]
     fn correct_z() {
-        let points = vec![
-            Point {
-                coordinates: vec![0.0, 0.0],
-            },
-            Point {
-                coordinates: vec![0.0, 2.0],
-            },
-        ];
+        let points = vec![Point::new(vec![0.0, 0.0]), Point::new(vec![0.0, 2.0])];
         let solution = ProblemSolution { points };
         assert_eq!(solution.get_z(), 2.0);
     }
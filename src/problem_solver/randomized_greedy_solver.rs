@@ -1,35 +1,25 @@
-use super::{Point, ProblemInstance, ProblemSolution, ProblemSolver};
-use std::cmp::min;
+use super::{
+    ConstructiveSolver, GreedyCriterion, ProblemInstance, ProblemSolution, ProblemSolver,
+    RestrictedCandidateList, SelectionBias, SelectionRule,
+};
 
 /// A greedy algorithm that creates a solution for the problem by adding
-/// to the solution in each step a random point from the k pointswhich
-/// are further from the center of the points already in the solution
+/// to the solution in each step a random point from the k points which
+/// are further from the center of the points already in the solution. The
+/// [restricted candidate list](super::RestrictedCandidateList) can also be value
+/// based, the choice can be biased towards the farthest candidates and other
+/// [criteria](super::GreedyCriterion) can be used to score the points. It is a
+/// [constructive solver](super::ConstructiveSolver) that chooses a candidate of the
+/// restricted candidate list
 pub struct RandomizedGreedySolver {
-    number_of_points: usize,
-    rcl_size: usize,
+    restricted_candidate_list: RestrictedCandidateList,
+    selection_bias: SelectionBias,
+    constructive_solver: ConstructiveSolver,
 }
 
 impl ProblemSolver for RandomizedGreedySolver {
     fn solve(&mut self, instance: &ProblemInstance) -> ProblemSolution {
-        assert!(instance.points.len() >= self.number_of_points);
-        let mut points = instance.points.clone();
-        let mut center = RandomizedGreedySolver::calculate_center(&points);
-        let mut solution_points = Vec::new();
-        while solution_points.len() < self.number_of_points {
-            let possible_points_indexes = RandomizedGreedySolver::get_farther_points_indexes(
-                &mut points,
-                &center,
-                self.rcl_size,
-            );
-            let new_point = points.remove(
-                possible_points_indexes[rand::random::<usize>() % possible_points_indexes.len()],
-            );
-            solution_points.push(new_point);
-            center = RandomizedGreedySolver::calculate_center(&solution_points);
-        }
-        ProblemSolution {
-            points: solution_points,
-        }
+        self.constructive_solver.solve(instance)
     }
 }
 
@@ -37,97 +27,79 @@ impl RandomizedGreedySolver {
     /// Creates a new solver. The number of points the solution will have and
     /// the size of the restricted candidate list need to be passed as arguments
     pub fn new(number_of_points: usize, rcl_size: usize) -> Self {
-        assert!(number_of_points > 0 && rcl_size > 0);
+        assert!(rcl_size > 0);
+        let restricted_candidate_list = RestrictedCandidateList::Cardinality(rcl_size);
+        let selection_bias = SelectionBias::default();
         RandomizedGreedySolver {
-            number_of_points,
-            rcl_size,
+            restricted_candidate_list,
+            selection_bias,
+            constructive_solver: ConstructiveSolver::new(
+                number_of_points,
+                GreedyCriterion::default(),
+            )
+            .with_selection_rule(SelectionRule::RestrictedCandidateList(
+                restricted_candidate_list,
+                selection_bias,
+            )),
         }
     }
 
-    fn calculate_center(points: &Vec<Point>) -> Point {
-        let coordinates = (0..points[0].get_dimensionality())
-            .map(|index| {
-                points
-                    .iter()
-                    .map(|point| point.coordinates[index])
-                    .sum::<f64>()
-                    / points.len() as f64
-            })
-            .collect();
-        Point::new(coordinates)
+    /// Uses a value based restricted candidate list with the specified alpha
+    /// instead of a fixed size
+    pub fn with_alpha(mut self, alpha: f64) -> Self {
+        assert!((0.0..=1.0).contains(&alpha));
+        self.restricted_candidate_list = RestrictedCandidateList::Value(alpha);
+        self.update_selection_rule()
     }
 
-    fn get_farther_points_indexes(
-        available_points: &Vec<Point>,
-        center: &Point,
-        number_of_points: usize,
-    ) -> Vec<usize> {
-        let mut result = Vec::with_capacity(number_of_points);
-        for _ in 0..min(number_of_points, available_points.len()) {
-            result.push(
-                available_points
-                    .iter()
-                    .enumerate()
-                    .filter(|(index, _)| !result.contains(index))
-                    .map(|(index, point)| (index, point.distance_to(center)))
-                    .reduce(|acc, value| {
-                        if value.1 > acc.1 {
-                            return value;
-                        }
-                        return acc;
-                    })
-                    // There must be at least one point in the vector
-                    .unwrap()
-                    .0,
-            );
-        }
-        result
+    /// Sets how the candidates are weighted by their rank when one is chosen. By
+    /// default all of them have the same probability
+    pub fn with_selection_bias(mut self, selection_bias: SelectionBias) -> Self {
+        self.selection_bias = selection_bias;
+        self.update_selection_rule()
+    }
+
+    /// Sets the criterion used to score the points. By default it is the distance
+    /// to the center of the solution
+    pub fn with_criterion(mut self, criterion: GreedyCriterion) -> Self {
+        self.constructive_solver = self.constructive_solver.with_scoring_function(criterion);
+        self
+    }
+
+    /// Seeds the random number generator, so the solutions are reproducible
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.constructive_solver = self.constructive_solver.with_seed(seed);
+        self
+    }
+
+    fn update_selection_rule(mut self) -> Self {
+        self.constructive_solver =
+            self.constructive_solver
+                .with_selection_rule(SelectionRule::RestrictedCandidateList(
+                    self.restricted_candidate_list,
+                    self.selection_bias,
+                ));
+        self
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    #[test]
-    fn center() {
-        let center = RandomizedGreedySolver::calculate_center(&vec![
-            Point {
-                coordinates: vec![0.0, 0.0],
-            },
-            Point {
-                coordinates: vec![2.0, 0.0],
-            },
-            Point {
-                coordinates: vec![0.0, 2.0],
-            },
-            Point {
-                coordinates: vec![2.0, 2.0],
-            },
-        ]);
-        assert_eq!((center.coordinates[0], center.coordinates[1]), (1.0, 1.0));
-    }
+    use crate::Point;
     #[test]
     fn furthest_point() {
-        let indexes = RandomizedGreedySolver::get_farther_points_indexes(
-            &mut vec![
-                Point {
-                    coordinates: vec![0.0, 0.0],
-                },
-                Point {
-                    coordinates: vec![3.0, 0.0],
-                },
-                Point {
-                    coordinates: vec![1.0, 2.0],
-                },
-                Point {
-                    coordinates: vec![2.0, 3.0],
-                },
-            ],
-            &Point {
-                coordinates: vec![1.0, 1.0],
-            },
-            2,
-        );
+        let center = Point::new(vec![1.0, 1.0]);
+        let distances = [
+            Point::new(vec![0.0, 0.0]),
+            Point::new(vec![3.0, 0.0]),
+            Point::new(vec![1.0, 2.0]),
+            Point::new(vec![2.0, 3.0]),
+        ]
+        .iter()
+        .map(|point| point.distance_to(&center))
+        .collect::<Vec<f64>>();
+        let indexes = RestrictedCandidateList::Cardinality(2).get_candidates(&distances);
         assert_eq!(indexes, vec![1, 3]);
     }
 }
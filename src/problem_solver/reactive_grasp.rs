@@ -0,0 +1,195 @@
+use super::{
+    local_search::LocalSearch, Incumbent, ProblemInstance, ProblemSolution, ProblemSolver,
+    RandomizedGreedySolver,
+};
+use rand::{distributions::WeightedIndex, prelude::Distribution, rngs::StdRng, Rng, SeedableRng};
+
+/// A implementation of a reactive GRASP. Instead of a fixed size of the restricted
+/// candidate list it has a set of possible sizes, and in each iteration it chooses
+/// one of them at random to create the solution. All of them start with the same
+/// probability, and periodically the probabilities are updated so the sizes that
+/// produced better solutions on average are chosen more often. The probability of
+/// each size is proportional to (average z / best z)^amplification
+pub struct ReactiveGRASP<L: LocalSearch> {
+    constructive_solvers: Vec<RandomizedGreedySolver>,
+    rcl_sizes: Vec<usize>,
+    probabilities: Vec<f64>,
+    local_search: L,
+    iterations: usize,
+    update_interval: usize,
+    amplification: f64,
+    rng: StdRng,
+    incumbent: Option<Incumbent>,
+}
+
+impl<L: LocalSearch> ProblemSolver for ReactiveGRASP<L> {
+    fn solve(&mut self, instance: &ProblemInstance) -> ProblemSolution {
+        let mut total_z = vec![0.0; self.rcl_sizes.len()];
+        let mut uses = vec![0usize; self.rcl_sizes.len()];
+        self.probabilities = vec![1.0 / self.rcl_sizes.len() as f64; self.rcl_sizes.len()];
+        let mut best_solution: Option<ProblemSolution> = None;
+        for iteration in 1..=self.iterations {
+            if iteration > 1 && self.is_stopped() {
+                break;
+            }
+            // The probabilities are always positive
+            let option = WeightedIndex::new(&self.probabilities)
+                .unwrap()
+                .sample(&mut self.rng);
+            let solution = self.constructive_solvers[option].solve(instance);
+            let solution = self.local_search.improve(instance, solution);
+            if let Some(incumbent) = &self.incumbent {
+                incumbent.offer_solution(&solution);
+            }
+            total_z[option] += solution.get_z();
+            uses[option] += 1;
+            match &best_solution {
+                Some(best_solution) if solution.get_z() <= best_solution.get_z() => {}
+                _ => best_solution = Some(solution),
+            }
+            if iteration % self.update_interval == 0 {
+                // The best solution is set in the first iteration
+                let best_z = best_solution.as_ref().unwrap().get_z();
+                self.update_probabilities(&total_z, &uses, best_z);
+            }
+        }
+        best_solution.unwrap()
+    }
+}
+
+impl<L: LocalSearch> ReactiveGRASP<L> {
+    /// Creates a new reactive GRASP with the specified arguments. The number of points
+    /// the solution will have, the possible sizes of the restricted candidate list,
+    /// the local search and the number of iterations
+    pub fn new(
+        number_of_points: usize,
+        rcl_sizes: Vec<usize>,
+        local_search: L,
+        iterations: usize,
+    ) -> Self {
+        assert!(!rcl_sizes.is_empty() && iterations > 0);
+        ReactiveGRASP {
+            constructive_solvers: rcl_sizes
+                .iter()
+                .map(|rcl_size| RandomizedGreedySolver::new(number_of_points, *rcl_size))
+                .collect(),
+            probabilities: vec![1.0 / rcl_sizes.len() as f64; rcl_sizes.len()],
+            rcl_sizes,
+            local_search,
+            iterations,
+            update_interval: 10,
+            amplification: 10.0,
+            rng: StdRng::from_entropy(),
+            incumbent: None,
+        }
+    }
+
+    /// Sets the number of iterations between two updates of the probabilities. By
+    /// default it is 10
+    pub fn with_update_interval(mut self, update_interval: usize) -> Self {
+        assert!(update_interval > 0);
+        self.update_interval = update_interval;
+        self
+    }
+
+    /// Sets the exponent used to calculate the probabilities. The greater it is the
+    /// more the best sizes are favoured. By default it is 10
+    pub fn with_amplification(mut self, amplification: f64) -> Self {
+        assert!(amplification >= 0.0);
+        self.amplification = amplification;
+        self
+    }
+
+    /// Seeds the random number generators, so the solutions are reproducible
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.rng = StdRng::seed_from_u64(seed);
+        let rng = &mut self.rng;
+        self.constructive_solvers = self
+            .constructive_solvers
+            .into_iter()
+            .map(|solver| solver.with_seed(rng.gen()))
+            .collect();
+        self
+    }
+
+    /// Offers the solution of each iteration to an [incumbent](super::Incumbent)
+    /// shared with other solvers, and stops after the current iteration when the
+    /// incumbent is stopped
+    pub fn with_incumbent(mut self, incumbent: Incumbent) -> Self {
+        self.incumbent = Some(incumbent);
+        self
+    }
+
+    /// The possible sizes of the restricted candidate list
+    pub fn get_rcl_sizes(&self) -> &[usize] {
+        &self.rcl_sizes
+    }
+
+    /// The probability of choosing each size of the restricted candidate list, in the
+    /// same order as the sizes. After solving an instance they are the final ones
+    pub fn get_probabilities(&self) -> &[f64] {
+        &self.probabilities
+    }
+
+    /// Whether the incumbent shared with other solvers has been stopped
+    fn is_stopped(&self) -> bool {
+        self.incumbent
+            .as_ref()
+            .is_some_and(|incumbent| incumbent.is_stopped())
+    }
+
+    /// Updates the probabilities with the average z of the solutions each size
+    /// produced. A size that hasn't been used yet is treated as if it had produced
+    /// the best solution, so it is still tried. Every size keeps a positive
+    /// probability
+    fn update_probabilities(&mut self, total_z: &[f64], uses: &[usize], best_z: f64) {
+        let qualities = total_z
+            .iter()
+            .zip(uses)
+            .map(|(total_z, uses)| {
+                if *uses == 0 || best_z <= 0.0 {
+                    return 1.0;
+                }
+                // A large amplification can make the quality underflow, so it is
+                // kept positive for the size to still be chosen sometimes
+                (total_z / *uses as f64 / best_z)
+                    .powf(self.amplification)
+                    .max(f64::MIN_POSITIVE)
+            })
+            .collect::<Vec<f64>>();
+        let total_quality = qualities.iter().sum::<f64>();
+        self.probabilities = qualities
+            .iter()
+            .map(|quality| quality / total_quality)
+            .collect();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::problem_solver::local_search::Swap;
+    #[test]
+    fn probabilities_are_a_distribution() {
+        let instance = ProblemInstance::from_file("problem_instances/max_div_20_2.txt").unwrap();
+        let mut solver = ReactiveGRASP::new(4, vec![1, 2, 3, 5], Swap::new(), 30)
+            .with_update_interval(5)
+            .with_seed(4);
+        let solution = solver.solve(&instance);
+        assert_eq!(solution.get_points().len(), 4);
+        assert_eq!(solver.get_probabilities().len(), 4);
+        assert!((solver.get_probabilities().iter().sum::<f64>() - 1.0).abs() < 1e-9);
+        assert!(solver.get_probabilities().iter().all(|p| *p > 0.0));
+    }
+    #[test]
+    fn large_amplification() {
+        let instance = ProblemInstance::from_file("problem_instances/max_div_30_3.txt").unwrap();
+        let mut solver = ReactiveGRASP::new(8, vec![2, 5, 10, 20], Swap::new(), 30)
+            .with_update_interval(5)
+            .with_amplification(1e6)
+            .with_seed(4);
+        solver.solve(&instance);
+        assert!((solver.get_probabilities().iter().sum::<f64>() - 1.0).abs() < 1e-9);
+        assert!(solver.get_probabilities().iter().all(|p| *p > 0.0));
+    }
+}
@@ -0,0 +1,104 @@
+/// How a [constructive solver](super::ConstructiveSolver), like the
+/// [randomized greedy solver](super::RandomizedGreedySolver), decides which points
+/// enter the restricted candidate list in each step. The candidates are ranked by
+/// the score of its [scoring function](super::ScoringFunction), which by default is
+/// the distance to the center of the points already in the solution
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RestrictedCandidateList {
+    /// The list has the specified number of best scored points
+    Cardinality(usize),
+    /// A point enters the list if its score is at least
+    /// max - alpha * (max - min), where max and min are the greatest and smallest
+    /// scores of the available points. Alpha must be between 0 and 1, 0 being
+    /// a pure greedy and 1 a pure random choice
+    Value(f64),
+}
+
+/// How the point added to the solution is chosen from the
+/// [restricted candidate list](RestrictedCandidateList). The weight of each
+/// candidate depends on its rank r, which is 1 for the best scored point
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum SelectionBias {
+    /// All the candidates have the same weight
+    #[default]
+    Uniform,
+    /// The weight is 1 / r
+    Linear,
+    /// The weight is 1 / ln(r + 1)
+    Logarithmic,
+    /// The weight is e^-r
+    Exponential,
+    /// The weight is r^-n, where n must be positive
+    Polynomial(f64),
+}
+
+impl RestrictedCandidateList {
+    /// Whether the size of the list or its alpha are valid
+    pub(super) fn is_valid(&self) -> bool {
+        match self {
+            RestrictedCandidateList::Cardinality(size) => *size > 0,
+            RestrictedCandidateList::Value(alpha) => (0.0..=1.0).contains(alpha),
+        }
+    }
+
+    /// Gets the indexes of the candidates ranked from the best to the worst scored,
+    /// given the scores of all the available points
+    pub(super) fn get_candidates(&self, scores: &[f64]) -> Vec<usize> {
+        let mut indexes = (0..scores.len()).collect::<Vec<usize>>();
+        indexes.sort_by(|a, b| scores[*b].total_cmp(&scores[*a]));
+        match self {
+            RestrictedCandidateList::Cardinality(size) => indexes.truncate(*size),
+            RestrictedCandidateList::Value(alpha) => {
+                if let (Some(first), Some(last)) = (indexes.first(), indexes.last()) {
+                    let (max, min) = (scores[*first], scores[*last]);
+                    let threshold = max - alpha * (max - min);
+                    indexes.retain(|index| scores[*index] >= threshold);
+                }
+            }
+        }
+        indexes
+    }
+}
+
+impl SelectionBias {
+    /// Whether the exponent of the polynomial bias is valid
+    pub(super) fn is_valid(&self) -> bool {
+        match self {
+            SelectionBias::Polynomial(n) => *n > 0.0 && n.is_finite(),
+            _ => true,
+        }
+    }
+
+    /// Gets the weight of the candidate with the specified rank, starting at 1
+    pub(super) fn get_weight(&self, rank: usize) -> f64 {
+        let rank = rank as f64;
+        match self {
+            SelectionBias::Uniform => 1.0,
+            SelectionBias::Linear => 1.0 / rank,
+            SelectionBias::Logarithmic => 1.0 / (rank + 1.0).ln(),
+            SelectionBias::Exponential => (-rank).exp(),
+            SelectionBias::Polynomial(n) => rank.powf(-n),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn candidates() {
+        let scores = [1.0, 4.0, 2.0, 3.5, 0.0];
+        assert_eq!(
+            RestrictedCandidateList::Cardinality(2).get_candidates(&scores),
+            vec![1, 3]
+        );
+        assert_eq!(
+            RestrictedCandidateList::Value(0.5).get_candidates(&scores),
+            vec![1, 3, 2]
+        );
+        assert_eq!(
+            RestrictedCandidateList::Value(0.0).get_candidates(&scores),
+            vec![1]
+        );
+    }
+}
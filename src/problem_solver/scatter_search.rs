@@ -0,0 +1,190 @@
+use super::{
+    local_search::LocalSearch, ElitePool, Incumbent, PathRelinking, ProblemInstance,
+    ProblemSolution, ProblemSolver, RandomizedGreedySolver,
+};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+/// A implementation of a scatter search. It creates a population of solutions with
+/// the [randomized greedy solver](super::RandomizedGreedySolver) improved by the local
+/// search, and keeps the best and most diverse ones in the reference set, which is an
+/// [elite pool](super::ElitePool). Then it combines every pair of solutions of the
+/// reference set with [path relinking](super::PathRelinking), improves the results
+/// with the local search and offers them to the reference set. The search stops when
+/// no new solution enters the reference set
+pub struct ScatterSearch<L: LocalSearch> {
+    number_of_points: usize,
+    reference_set_size: usize,
+    population_size: usize,
+    local_search: L,
+    rcl_size: usize,
+    min_distance: usize,
+    path_relinking: PathRelinking,
+    rng: StdRng,
+    incumbent: Option<Incumbent>,
+}
+
+impl<L: LocalSearch> ProblemSolver for ScatterSearch<L> {
+    fn solve(&mut self, instance: &ProblemInstance) -> ProblemSolution {
+        let mut constructive_solver =
+            RandomizedGreedySolver::new(self.number_of_points, self.rcl_size)
+                .with_seed(self.rng.gen());
+        let mut reference_set =
+            ElitePool::new(self.reference_set_size).with_min_distance(self.min_distance);
+        for iteration in 0..self.population_size {
+            if iteration > 0 && self.is_stopped() {
+                break;
+            }
+            let solution = constructive_solver.solve(instance);
+            let solution = self.local_search.improve(instance, solution);
+            self.offer_to_incumbent(&solution);
+            reference_set.offer_solution(solution);
+        }
+        loop {
+            let solutions = reference_set.get_solutions().to_vec();
+            let mut new_solutions = false;
+            for (index, solution1) in solutions.iter().enumerate() {
+                for solution2 in &solutions[index + 1..] {
+                    if self.is_stopped() {
+                        break;
+                    }
+                    let solution = self.path_relinking.relink(instance, solution1, solution2);
+                    let solution = self.local_search.improve(instance, solution);
+                    self.offer_to_incumbent(&solution);
+                    new_solutions |= reference_set.offer_solution(solution);
+                }
+            }
+            if !new_solutions || self.is_stopped() {
+                // The reference set has at least one solution
+                return reference_set.get_best_solution().unwrap().clone();
+            }
+        }
+    }
+}
+
+impl<L: LocalSearch> ScatterSearch<L> {
+    /// Creates a new scatter search with the specified arguments. The number of points
+    /// the solution will have, the size of the reference set and the local search. The
+    /// initial population is ten times the size of the reference set
+    pub fn new(number_of_points: usize, reference_set_size: usize, local_search: L) -> Self {
+        assert!(number_of_points > 0 && reference_set_size > 1);
+        ScatterSearch {
+            number_of_points,
+            reference_set_size,
+            population_size: 10 * reference_set_size,
+            local_search,
+            rcl_size: 3,
+            min_distance: 1,
+            path_relinking: PathRelinking::default(),
+            rng: StdRng::from_entropy(),
+            incumbent: None,
+        }
+    }
+
+    /// Sets the number of solutions created for the initial population
+    pub fn with_population_size(mut self, population_size: usize) -> Self {
+        assert!(population_size > 0);
+        self.population_size = population_size;
+        self
+    }
+
+    /// Sets the size of the restricted candidate list of the solver that creates the
+    /// initial population. By default it is 3
+    pub fn with_rcl_size(mut self, rcl_size: usize) -> Self {
+        assert!(rcl_size > 0);
+        self.rcl_size = rcl_size;
+        self
+    }
+
+    /// Sets the minimum distance between the solutions of the reference set. By
+    /// default they only need to be different
+    pub fn with_min_distance(mut self, min_distance: usize) -> Self {
+        assert!(min_distance > 0);
+        self.min_distance = min_distance;
+        self
+    }
+
+    /// Sets the strategy used to combine the solutions. By default it is the mixed
+    /// path relinking
+    pub fn with_path_relinking(mut self, path_relinking: PathRelinking) -> Self {
+        self.path_relinking = path_relinking;
+        self
+    }
+
+    /// Seeds the random number generator, so the solutions are reproducible
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.rng = StdRng::seed_from_u64(seed);
+        self
+    }
+
+    /// Offers the improved solutions to an [incumbent](super::Incumbent) shared with
+    /// other solvers, and stops as soon as the incumbent is stopped, returning the best
+    /// solution of the reference set
+    pub fn with_incumbent(mut self, incumbent: Incumbent) -> Self {
+        self.incumbent = Some(incumbent);
+        self
+    }
+
+    /// Whether the incumbent shared with other solvers has been stopped
+    fn is_stopped(&self) -> bool {
+        self.incumbent
+            .as_ref()
+            .is_some_and(|incumbent| incumbent.is_stopped())
+    }
+
+    /// Offers a solution to the incumbent, if there is one
+    fn offer_to_incumbent(&self, solution: &ProblemSolution) {
+        if let Some(incumbent) = &self.incumbent {
+            incumbent.offer_solution(solution);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::problem_solver::{local_search::Swap, BranchAndBound};
+    #[test]
+    fn reaches_optimum() {
+        let instance = ProblemInstance::from_file("problem_instances/max_div_15_2.txt").unwrap();
+        let optimum = BranchAndBound::new(4).solve(&instance);
+        let solution = ScatterSearch::new(4, 4, Swap::new())
+            .with_seed(1)
+            .solve(&instance);
+        assert!((solution.get_z() - optimum.get_z()).abs() < 1e-9);
+    }
+    #[test]
+    fn fixed_and_forbidden_points() {
+        let instance = ProblemInstance::from_file("problem_instances/max_div_20_2.txt")
+            .unwrap()
+            .with_fixed_points(vec![0, 5])
+            .with_forbidden_points(vec![1, 2, 3]);
+        let optimum = BranchAndBound::new(4).solve(&instance);
+        let solution = ScatterSearch::new(4, 4, Swap::new())
+            .with_seed(1)
+            .solve(&instance);
+        assert!(solution.is_feasible(&instance, 4));
+        assert!(solution.get_z() <= optimum.get_z() + 1e-9);
+    }
+    #[test]
+    fn capacity() {
+        let instance =
+            ProblemInstance::from_file("problem_instances/max_div_15_2_capacity.txt").unwrap();
+        let optimum = BranchAndBound::new(15).solve(&instance);
+        let solution = ScatterSearch::new(15, 4, Swap::new())
+            .with_seed(1)
+            .solve(&instance);
+        assert!(solution.is_feasible(&instance, 15));
+        assert!(solution.get_z() <= optimum.get_z() + 1e-9);
+    }
+    #[test]
+    fn group_limits() {
+        let instance =
+            ProblemInstance::from_file("problem_instances/max_div_20_2_balanced.txt").unwrap();
+        let optimum = BranchAndBound::new(4).solve(&instance);
+        let solution = ScatterSearch::new(4, 4, Swap::new())
+            .with_seed(1)
+            .solve(&instance);
+        assert!(solution.is_feasible(&instance, 4));
+        assert!(solution.get_z() <= optimum.get_z() + 1e-9);
+    }
+}
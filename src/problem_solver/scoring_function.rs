@@ -0,0 +1,21 @@
+use super::{Point, ProblemInstance};
+
+/// A trait for the function a [constructive solver](super::ConstructiveSolver)
+/// uses to score the candidate points in each step. A greater score is better
+pub trait ScoringFunction {
+    /// Gets the score of each candidate given the points already in the solution,
+    /// in the same order as the candidates
+    fn get_scores(
+        &self,
+        instance: &ProblemInstance,
+        candidates: &[Point],
+        solution: &[Point],
+    ) -> Vec<f64>;
+
+    /// Whether the construction is destructive. A destructive construction starts
+    /// with all the points in the solution and in each step drops the selected
+    /// candidate, so the candidates are the points of the solution
+    fn is_destructive(&self) -> bool {
+        false
+    }
+}
@@ -0,0 +1,72 @@
+use super::{RestrictedCandidateList, SelectionBias};
+use rand::{distributions::WeightedIndex, prelude::Distribution, Rng};
+
+/// How a [constructive solver](super::ConstructiveSolver) chooses a candidate
+/// from their scores
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum SelectionRule {
+    /// The candidate with the greatest score is chosen
+    #[default]
+    Best,
+    /// A candidate of the [restricted candidate list](super::RestrictedCandidateList)
+    /// is chosen at random, weighted by its rank with the selection bias
+    RestrictedCandidateList(RestrictedCandidateList, SelectionBias),
+    /// A candidate is chosen with probability proportional to the difference between
+    /// its score and the smallest score. If all the scores are the same every
+    /// candidate has the same probability
+    Roulette,
+}
+
+impl SelectionRule {
+    /// Gets the index of the chosen candidate. There must be at least one
+    pub(super) fn select<R: Rng>(&self, scores: &[f64], rng: &mut R) -> usize {
+        match self {
+            SelectionRule::Best => {
+                scores
+                    .iter()
+                    .enumerate()
+                    .reduce(|acc, value| {
+                        if value.1 > acc.1 {
+                            return value;
+                        }
+                        acc
+                    })
+                    .unwrap()
+                    .0
+            }
+            SelectionRule::RestrictedCandidateList(restricted_candidate_list, selection_bias) => {
+                let possible_points_indexes = restricted_candidate_list.get_candidates(scores);
+                let weights =
+                    (1..=possible_points_indexes.len()).map(|rank| selection_bias.get_weight(rank));
+                // The list always has at least the best point, and all weights are positive
+                let choice = WeightedIndex::new(weights).unwrap().sample(rng);
+                possible_points_indexes[choice]
+            }
+            SelectionRule::Roulette => {
+                let min_score = scores.iter().cloned().fold(f64::INFINITY, f64::min);
+                match WeightedIndex::new(scores.iter().map(|score| score - min_score)) {
+                    Ok(distribution) => distribution.sample(rng),
+                    Err(_) => rng.gen_range(0..scores.len()),
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{rngs::StdRng, SeedableRng};
+    #[test]
+    fn roulette_never_chooses_the_worst_candidate() {
+        let mut rng = StdRng::seed_from_u64(0);
+        assert_eq!(SelectionRule::Best.select(&[1.0, 3.0, 2.0], &mut rng), 1);
+        for _ in 0..100 {
+            assert_ne!(
+                SelectionRule::Roulette.select(&[1.0, 3.0, 2.0], &mut rng),
+                0
+            );
+        }
+        assert!(SelectionRule::Roulette.select(&[1.0, 1.0], &mut rng) < 2);
+    }
+}
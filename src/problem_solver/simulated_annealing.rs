@@ -0,0 +1,309 @@
+use super::local_search::{add_best_fitting_point, get_swap_delta, is_possible_swap};
+use super::{
+    CoolingSchedule, GreedySolver, Incumbent, Point, ProblemInstance, ProblemSolution,
+    ProblemSolver,
+};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+/// The number of random swaps used to calibrate the initial temperature
+const CALIBRATION_SAMPLES: usize = 100;
+
+/// A implementation of a simulated annealing over the environment of the
+/// [swap](super::local_search::Swap) local search. It starts from the solution of
+/// the [greedy solver](super::GreedySolver) and in each step it chooses a random
+/// swap, which is performed if it improves the solution or, with a probability that
+/// decreases with the temperature, if it makes it worse. The swaps the local
+/// searches can't perform, like the ones of a fixed point, are rejected. The
+/// temperature is lowered with the [cooling schedule](super::CoolingSchedule) after
+/// a number of steps. If the instance has a capacity the best solution is completed
+/// with the points that fit
+pub struct SimulatedAnnealing {
+    number_of_points: usize,
+    steps_per_level: usize,
+    levels: usize,
+    cooling_schedule: CoolingSchedule,
+    initial_temperature: Option<f64>,
+    initial_acceptance_probability: f64,
+    reheating: Option<(usize, f64)>,
+    rng: StdRng,
+    incumbent: Option<Incumbent>,
+}
+
+impl ProblemSolver for SimulatedAnnealing {
+    fn solve(&mut self, instance: &ProblemInstance) -> ProblemSolution {
+        let mut solution = GreedySolver::new(self.number_of_points).solve(instance);
+        let mut outside_points = instance
+            .points
+            .iter()
+            .enumerate()
+            .filter(|(index, point)| {
+                !instance.is_forbidden_index(*index) && !solution.points.contains(point)
+            })
+            .map(|(_, point)| point.clone())
+            .collect::<Vec<Point>>();
+        // Without points on either side there isn't any swap, so the temperature
+        // can't be calibrated either
+        if solution.points.is_empty() || outside_points.is_empty() {
+            return solution;
+        }
+        let initial_temperature = match self.initial_temperature {
+            Some(temperature) => temperature,
+            None => self.calibrate_temperature(instance, &solution, &outside_points),
+        };
+        let mut weight = solution.get_weight();
+        let mut counts = instance.get_group_counts(&solution.points);
+        let mut temperature = initial_temperature;
+        let mut z = solution.get_z();
+        let mut best_solution = solution.clone();
+        let mut best_z = z;
+        let mut levels_without_improvement = 0;
+        'levels: for _ in 0..self.levels {
+            let mut improved = false;
+            let mut visited_z = Vec::with_capacity(self.steps_per_level);
+            for _ in 0..self.steps_per_level {
+                if self.is_stopped() {
+                    break 'levels;
+                }
+                let index = self.rng.gen_range(0..solution.points.len());
+                let outside_index = self.rng.gen_range(0..outside_points.len());
+                let point = &outside_points[outside_index];
+                if !is_possible_swap(instance, &solution, weight, &counts, index, point) {
+                    visited_z.push(z);
+                    continue;
+                }
+                let delta = get_swap_delta(&solution, index, point);
+                if delta > 0.0
+                    || (temperature > 0.0 && self.rng.gen::<f64>() < (delta / temperature).exp())
+                {
+                    let leaving_point = &solution.points[index];
+                    weight += point.weight - leaving_point.weight;
+                    *counts.get_mut(&leaving_point.group).unwrap() -= 1;
+                    *counts.entry(point.group).or_insert(0) += 1;
+                    std::mem::swap(
+                        &mut solution.points[index],
+                        &mut outside_points[outside_index],
+                    );
+                    z += delta;
+                    if z > best_z {
+                        best_solution = solution.clone();
+                        best_z = z;
+                        improved = true;
+                    }
+                }
+                visited_z.push(z);
+            }
+            if improved {
+                if let Some(incumbent) = &self.incumbent {
+                    incumbent.offer_solution(&best_solution);
+                }
+            }
+            temperature = self.cooling_schedule.cool(
+                temperature,
+                initial_temperature,
+                self.levels,
+                SimulatedAnnealing::get_standard_deviation(&visited_z),
+            );
+            levels_without_improvement = if improved {
+                0
+            } else {
+                levels_without_improvement + 1
+            };
+            if let Some((levels, fraction)) = self.reheating {
+                if levels_without_improvement >= levels {
+                    temperature = temperature.max(initial_temperature * fraction);
+                    levels_without_improvement = 0;
+                }
+            }
+        }
+        while add_best_fitting_point(instance, &mut best_solution) {}
+        best_solution
+    }
+}
+
+impl SimulatedAnnealing {
+    /// Creates a new simulated annealing with the specified arguments. The number of
+    /// points the solution will have, the number of steps performed with each
+    /// temperature and the number of temperature levels
+    pub fn new(number_of_points: usize, steps_per_level: usize, levels: usize) -> Self {
+        assert!(number_of_points > 0 && steps_per_level > 0);
+        SimulatedAnnealing {
+            number_of_points,
+            steps_per_level,
+            levels,
+            cooling_schedule: CoolingSchedule::default(),
+            initial_temperature: None,
+            initial_acceptance_probability: 0.8,
+            reheating: None,
+            rng: StdRng::from_entropy(),
+            incumbent: None,
+        }
+    }
+
+    /// Sets the cooling schedule. By default the temperature is multiplied by 0.95
+    /// after each level
+    pub fn with_cooling_schedule(mut self, cooling_schedule: CoolingSchedule) -> Self {
+        match cooling_schedule {
+            CoolingSchedule::Geometric(factor) => assert!(factor > 0.0 && factor < 1.0),
+            CoolingSchedule::Adaptive(speed) => assert!(speed > 0.0),
+            CoolingSchedule::Linear => {}
+        }
+        self.cooling_schedule = cooling_schedule;
+        self
+    }
+
+    /// Sets the initial temperature instead of calibrating it
+    pub fn with_initial_temperature(mut self, initial_temperature: f64) -> Self {
+        assert!(initial_temperature >= 0.0);
+        self.initial_temperature = Some(initial_temperature);
+        self
+    }
+
+    /// Sets the probability with which the calibrated initial temperature accepts
+    /// an average worsening swap. By default it is 0.8
+    pub fn with_initial_acceptance_probability(mut self, probability: f64) -> Self {
+        assert!(probability > 0.0 && probability < 1.0);
+        self.initial_acceptance_probability = probability;
+        self
+    }
+
+    /// Raises the temperature to the specified fraction of the initial temperature
+    /// when the best solution hasn't improved for the specified number of levels
+    pub fn with_reheating(mut self, levels: usize, fraction: f64) -> Self {
+        assert!(levels > 0 && fraction > 0.0);
+        self.reheating = Some((levels, fraction));
+        self
+    }
+
+    /// Seeds the random number generator, so the solutions are reproducible
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.rng = StdRng::seed_from_u64(seed);
+        self
+    }
+
+    /// Offers the best solution after each temperature level to an
+    /// [incumbent](super::Incumbent) shared with other solvers, and stops as soon as
+    /// the incumbent is stopped, returning the best solution found so far
+    pub fn with_incumbent(mut self, incumbent: Incumbent) -> Self {
+        self.incumbent = Some(incumbent);
+        self
+    }
+
+    /// Whether the incumbent shared with other solvers has been stopped
+    fn is_stopped(&self) -> bool {
+        self.incumbent
+            .as_ref()
+            .is_some_and(|incumbent| incumbent.is_stopped())
+    }
+
+    /// Calculates the initial temperature from a sample of random swaps of the
+    /// initial solution, so that a swap that makes the solution as worse as the
+    /// average worsening swap is accepted with the initial acceptance probability.
+    /// The swaps that can't be performed are left out of the sample
+    fn calibrate_temperature(
+        &mut self,
+        instance: &ProblemInstance,
+        solution: &ProblemSolution,
+        outside_points: &[Point],
+    ) -> f64 {
+        let weight = solution.get_weight();
+        let counts = instance.get_group_counts(&solution.points);
+        let worsening_deltas = (0..CALIBRATION_SAMPLES)
+            .filter_map(|_| {
+                let index = self.rng.gen_range(0..solution.points.len());
+                let point = &outside_points[self.rng.gen_range(0..outside_points.len())];
+                is_possible_swap(instance, solution, weight, &counts, index, point)
+                    .then(|| get_swap_delta(solution, index, point))
+            })
+            .filter(|delta| *delta < 0.0)
+            .collect::<Vec<f64>>();
+        if worsening_deltas.is_empty() {
+            return 0.0;
+        }
+        let average_delta = worsening_deltas.iter().sum::<f64>() / worsening_deltas.len() as f64;
+        average_delta / self.initial_acceptance_probability.ln()
+    }
+
+    fn get_standard_deviation(values: &[f64]) -> f64 {
+        let mean = values.iter().sum::<f64>() / values.len() as f64;
+        (values
+            .iter()
+            .map(|value| (value - mean).powi(2))
+            .sum::<f64>()
+            / values.len() as f64)
+            .sqrt()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::problem_solver::BranchAndBound;
+    #[test]
+    fn never_worse_than_the_greedy_solution() {
+        let instance = ProblemInstance::from_file("problem_instances/max_div_20_2.txt").unwrap();
+        let greedy_solution = GreedySolver::new(4).solve(&instance);
+        for cooling_schedule in [
+            CoolingSchedule::Geometric(0.9),
+            CoolingSchedule::Linear,
+            CoolingSchedule::Adaptive(0.5),
+        ] {
+            let solution = SimulatedAnnealing::new(4, 50, 20)
+                .with_cooling_schedule(cooling_schedule)
+                .with_reheating(3, 0.5)
+                .with_seed(5)
+                .solve(&instance);
+            assert_eq!(solution.get_points().len(), 4);
+            assert!(solution.get_z() >= greedy_solution.get_z());
+        }
+    }
+    #[test]
+    fn fixed_and_forbidden_points() {
+        let instance = ProblemInstance::from_file("problem_instances/max_div_20_2.txt")
+            .unwrap()
+            .with_fixed_points(vec![0, 5])
+            .with_forbidden_points(vec![1, 2, 3]);
+        let solution = SimulatedAnnealing::new(4, 50, 20)
+            .with_seed(5)
+            .solve(&instance);
+        assert!(solution.is_feasible(&instance, 4));
+    }
+    #[test]
+    fn every_point() {
+        let instance = ProblemInstance::from_file("problem_instances/max_div_15_2.txt").unwrap();
+        let solution = SimulatedAnnealing::new(15, 50, 20)
+            .with_seed(5)
+            .solve(&instance);
+        assert_eq!(solution.get_points().len(), 15);
+    }
+    #[test]
+    fn capacity() {
+        let instance =
+            ProblemInstance::from_file("problem_instances/max_div_15_2_capacity.txt").unwrap();
+        let optimum = BranchAndBound::new(15).solve(&instance);
+        let solution = SimulatedAnnealing::new(15, 50, 20)
+            .with_seed(5)
+            .solve(&instance);
+        assert!(solution.is_feasible(&instance, 15));
+        assert!(solution.get_z() <= optimum.get_z() + 1e-9);
+    }
+    #[test]
+    fn capacity_below_every_weight() {
+        let instance = ProblemInstance::from_file("problem_instances/max_div_15_2_weighted.txt")
+            .unwrap()
+            .with_capacity(0.5);
+        let solution = SimulatedAnnealing::new(4, 50, 20)
+            .with_seed(5)
+            .solve(&instance);
+        assert!(solution.get_points().is_empty());
+        assert!(solution.is_feasible(&instance, 4));
+    }
+    #[test]
+    fn group_limits() {
+        let instance =
+            ProblemInstance::from_file("problem_instances/max_div_20_2_balanced.txt").unwrap();
+        let solution = SimulatedAnnealing::new(4, 50, 20)
+            .with_seed(5)
+            .solve(&instance);
+        assert!(solution.is_feasible(&instance, 4));
+    }
+}
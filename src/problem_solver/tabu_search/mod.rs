@@ -0,0 +1,713 @@
+use super::checkpoint::{Checkpoint, CheckpointError};
+use super::local_search::{get_swap_delta, Swap};
+use super::{ElitePool, Incumbent, Point, ProblemInstance, ProblemSolution, ProblemSolver, GRASP};
+use rand::{seq::SliceRandom, Rng, SeedableRng};
+use rand_chacha::ChaCha12Rng;
+use std::path::Path;
+
+mod tabu_list;
+use tabu_list::TabuList;
+mod tabu_search_state;
+use tabu_search_state::SearchMemory;
+pub use tabu_search_state::TabuSearchState;
+mod restart_strategy;
+pub use restart_strategy::RestartStrategy;
+
+type CreateSolver<'a> = Box<dyn FnMut(u64) -> Box<dyn ProblemSolver + 'a> + Send + 'a>;
+
+/// A implementation of a tabu search. By default it uses [GRASP](super::GRASP) to get
+/// an initial solution, but any solver or a given solution can be used instead, and
+/// then performs the search. Besides the short term memory of the tabu
+/// list it has a long term memory that counts how many times each point has entered
+/// the solution, which can be used to penalize frequent points and to diversify the
+/// restarts of the multiboot, and an elite pool used to intensify them
+pub struct TabuSearch<'a> {
+    number_of_points: usize,
+    initial_solver: Option<Box<dyn ProblemSolver + Send + 'a>>,
+    create_initial_solver: Option<CreateSolver<'a>>,
+    initial_solution: Option<ProblemSolution>,
+    add_tenure: (usize, usize),
+    drop_tenure: (usize, usize),
+    pair_tabu: bool,
+    iterations: usize,
+    inner_iterations: usize,
+    frequency_penalty: f64,
+    restart_strategy: RestartStrategy,
+    elite_pool_size: usize,
+    seed: u64,
+    rng: ChaCha12Rng,
+    state: Option<TabuSearchState>,
+    checkpoint: Option<Checkpoint>,
+    incumbent: Option<Incumbent>,
+}
+
+impl<'a> ProblemSolver for TabuSearch<'a> {
+    fn solve(&mut self, instance: &ProblemInstance) -> ProblemSolution {
+        assert!(instance.get_capacity().is_some() || self.number_of_points < instance.points.len());
+        assert!(instance.is_feasible(self.number_of_points));
+        let mut state = match self.state.take() {
+            Some(state) => {
+                self.seed = state.seed;
+                self.rng = ChaCha12Rng::seed_from_u64(state.seed);
+                self.rng.set_word_pos(state.word_pos);
+                state
+            }
+            None => TabuSearchState::new(self.seed, instance.points.len()),
+        };
+        while state.iteration < self.iterations {
+            if state.memory.is_none() {
+                let solution = self.get_initial_solution(instance, &state);
+                if let Some(incumbent) = &self.incumbent {
+                    incumbent.offer_solution(&solution);
+                }
+                state.memory = Some(SearchMemory::new(solution, instance));
+            }
+            self.perform_search(instance, &mut state);
+            // The memory has been created before the search
+            let solution = state.memory.take().unwrap().best_solution;
+            let mut elite_pool = ElitePool::new(self.elite_pool_size);
+            for elite_solution in state.elite_solutions.drain(..) {
+                elite_pool.offer_solution(elite_solution);
+            }
+            elite_pool.offer_solution(solution.clone());
+            state.elite_solutions = elite_pool.get_solutions().to_vec();
+            match &state.best_solution {
+                Some(best_solution) if solution.get_z() <= best_solution.get_z() => {}
+                _ => state.best_solution = Some(solution),
+            }
+            state.iteration += 1;
+            if self.is_stopped() {
+                break;
+            }
+        }
+        state.best_solution.unwrap()
+    }
+}
+
+impl<'a> TabuSearch<'a> {
+    /// Creates a new tabu search with the specified arguments. The number of points
+    /// the solution will have, the tabu tenure of the points that leave the solution,
+    /// the number of iterations of the multiboot and the number of iterations without
+    /// improvement for the actual search
+    pub fn new(
+        number_of_points: usize,
+        tenure: usize,
+        iterations: usize,
+        inner_iterations: usize,
+    ) -> Self {
+        assert!(number_of_points > 0 && tenure > 0);
+        let seed = rand::random();
+        TabuSearch {
+            number_of_points,
+            initial_solver: None,
+            create_initial_solver: None,
+            initial_solution: None,
+            add_tenure: (tenure, tenure),
+            drop_tenure: (0, 0),
+            pair_tabu: false,
+            iterations,
+            inner_iterations,
+            frequency_penalty: 0.0,
+            restart_strategy: RestartStrategy::default(),
+            elite_pool_size: 5,
+            seed,
+            rng: ChaCha12Rng::seed_from_u64(seed),
+            state: None,
+            checkpoint: None,
+            incumbent: None,
+        }
+    }
+
+    /// Seeds the random number generator, so the solutions are reproducible
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self.rng = ChaCha12Rng::seed_from_u64(seed);
+        self
+    }
+
+    /// Starts the first search of the multiboot from the given solution. It is only
+    /// used if its points belong to the instance the search solves and it is
+    /// feasible with the number of points of the search, otherwise the first
+    /// solution is constructed as usual
+    pub fn with_initial_solution(mut self, solution: ProblemSolution) -> Self {
+        self.initial_solution = Some(solution);
+        self
+    }
+
+    /// Uses the given [solver](super::ProblemSolver) to construct the initial
+    /// solutions instead of GRASP. The state of the solver isn't saved by the
+    /// checkpoints, so a resumed search only behaves like the interrupted one if the
+    /// solver always returns the same solution. Randomized solvers should be added
+    /// with [with_seeded_initial_solver](TabuSearch::with_seeded_initial_solver)
+    pub fn with_initial_solver<S: ProblemSolver + Send + 'a>(mut self, solver: S) -> Self {
+        self.initial_solver = Some(Box::new(solver));
+        self.create_initial_solver = None;
+        self
+    }
+
+    /// Constructs the initial solutions with a new solver each time, created by the
+    /// given function from a seed drawn from the random number generator of the
+    /// search. The seeds are part of the checkpoints, so a resumed search constructs
+    /// the same solutions as the interrupted one
+    pub fn with_seeded_initial_solver<S, F>(mut self, mut create_solver: F) -> Self
+    where
+        S: ProblemSolver + 'a,
+        F: FnMut(u64) -> S + Send + 'a,
+    {
+        self.create_initial_solver = Some(Box::new(move |seed| Box::new(create_solver(seed))));
+        self.initial_solver = None;
+        self
+    }
+
+    /// Sets the range of the tenure of the points that leave the solution, which
+    /// can't be added again during that number of moves. Each time a point leaves
+    /// the solution its tenure is chosen at random from the range
+    pub fn with_add_tenure_range(mut self, min_tenure: usize, max_tenure: usize) -> Self {
+        assert!(min_tenure > 0 && min_tenure <= max_tenure);
+        self.add_tenure = (min_tenure, max_tenure);
+        self
+    }
+
+    /// Sets the range of the tenure of the points that enter the solution, which
+    /// can't be dropped during that number of moves. Each time a point enters the
+    /// solution its tenure is chosen at random from the range. By default the points
+    /// that enter the solution aren't tabu
+    pub fn with_drop_tenure_range(mut self, min_tenure: usize, max_tenure: usize) -> Self {
+        assert!(min_tenure <= max_tenure);
+        self.drop_tenure = (min_tenure, max_tenure);
+        self
+    }
+
+    /// Makes whole swaps tabu instead of single points. After a swap, the swap that
+    /// reverses it is tabu for the add tenure, but the points involved can still be
+    /// part of other swaps
+    pub fn with_pair_tabu(mut self) -> Self {
+        self.pair_tabu = true;
+        self
+    }
+
+    /// Penalizes the swaps that add points which have entered the solution often.
+    /// The swaps are compared by their z minus the weight multiplied by the fraction
+    /// of the moves in which the entering point was added. Swaps that fulfill the
+    /// aspiration criteria are still compared by their z
+    pub fn with_frequency_penalty(mut self, weight: f64) -> Self {
+        assert!(weight >= 0.0);
+        self.frequency_penalty = weight;
+        self
+    }
+
+    /// Sets how the searches of the multiboot after the first one get their initial
+    /// solution. By default a new solution is constructed for each one
+    pub fn with_restart_strategy(mut self, restart_strategy: RestartStrategy) -> Self {
+        self.restart_strategy = restart_strategy;
+        self
+    }
+
+    /// Sets the number of solutions kept in the elite pool used by the
+    /// intensification restarts. By default it is 5
+    pub fn with_elite_pool_size(mut self, elite_pool_size: usize) -> Self {
+        assert!(elite_pool_size > 0);
+        self.elite_pool_size = elite_pool_size;
+        self
+    }
+
+    /// Offers the improvements of the search to an [incumbent](super::Incumbent)
+    /// shared with other solvers, and stops as soon as the incumbent is stopped,
+    /// returning the best solution found so far
+    pub fn with_incumbent(mut self, incumbent: Incumbent) -> Self {
+        self.incumbent = Some(incumbent);
+        self
+    }
+
+    /// Saves the [state](TabuSearchState) of the search to the specified file each
+    /// time the specified number of moves has been performed, so it can be
+    /// [loaded](TabuSearchState::load) and continued if the search is interrupted
+    pub fn with_checkpoint<P: AsRef<Path>>(mut self, path: P, interval: usize) -> Self {
+        self.checkpoint = Some(Checkpoint::new(path, interval));
+        self
+    }
+
+    /// Continues the search from a [state](TabuSearchState) saved by a checkpoint.
+    /// The search must be created with the same arguments as the one that saved it,
+    /// and it will behave exactly like that search would have done
+    pub fn with_state(mut self, state: TabuSearchState) -> Self {
+        self.state = Some(state);
+        self
+    }
+
+    /// Gets the last error that happened while saving a checkpoint. The search
+    /// doesn't stop when a checkpoint can't be saved
+    pub fn get_checkpoint_error(&self) -> Option<&CheckpointError> {
+        self.checkpoint
+            .as_ref()
+            .and_then(|checkpoint| checkpoint.get_error())
+    }
+
+    /// Gets the solution a search of the multiboot starts from, depending on the
+    /// restart strategy
+    fn get_initial_solution(
+        &mut self,
+        instance: &ProblemInstance,
+        state: &TabuSearchState,
+    ) -> ProblemSolution {
+        if state.iteration == 0 {
+            if let Some(solution) = self.initial_solution.as_ref().filter(|solution| {
+                solution.is_from_instance(instance)
+                    && solution.is_feasible(instance, self.number_of_points)
+            }) {
+                return solution.clone();
+            }
+        }
+        let restart_strategy = match self.restart_strategy {
+            _ if state.iteration == 0 => RestartStrategy::Construction,
+            RestartStrategy::Alternating if state.iteration % 2 == 1 => {
+                RestartStrategy::Intensification
+            }
+            RestartStrategy::Alternating => RestartStrategy::Diversification,
+            restart_strategy => restart_strategy,
+        };
+        match restart_strategy {
+            RestartStrategy::Intensification if !state.elite_solutions.is_empty() => {
+                let index = self.rng.gen_range(0..state.elite_solutions.len());
+                state.elite_solutions[index].clone()
+            }
+            RestartStrategy::Diversification => {
+                let number_of_points = instance.get_max_points(self.number_of_points);
+                let mut indexes = (0..instance.points.len())
+                    .filter(|index| !instance.is_forbidden_index(*index))
+                    .collect::<Vec<usize>>();
+                indexes.shuffle(&mut self.rng);
+                indexes.sort_by_key(|index| {
+                    let fixed = instance.is_fixed_index(*index);
+                    (!fixed, state.frequencies[*index])
+                });
+                // The fixed points come first, so they always fit. A point skipped to
+                // leave room for the minimums of the groups can be added in a later pass
+                let mut points = Vec::with_capacity(number_of_points);
+                let mut weight = 0.0;
+                let mut added = true;
+                while added && points.len() < number_of_points {
+                    added = false;
+                    for index in indexes.iter() {
+                        let point = &instance.points[*index];
+                        let counts = instance.get_group_counts(&points);
+                        if points.len() < number_of_points
+                            && !points.contains(point)
+                            && instance.fits(weight + point.weight)
+                            && instance.can_add_to_group(
+                                &counts,
+                                point.group,
+                                number_of_points - points.len() - 1,
+                            )
+                        {
+                            weight += point.weight;
+                            points.push(point.clone());
+                            added = true;
+                        }
+                    }
+                }
+                ProblemSolution { points }
+            }
+            _ => match (
+                self.initial_solver.as_mut(),
+                self.create_initial_solver.as_mut(),
+            ) {
+                (Some(solver), _) => solver.solve(instance),
+                (None, Some(create_solver)) => create_solver(self.rng.gen()).solve(instance),
+                (None, None) => GRASP::new(self.number_of_points, 2, Swap::new(), 1)
+                    .with_seed(self.rng.gen())
+                    .solve(instance),
+            },
+        }
+    }
+
+    /// Performs the tabu search with the specified number of iterations(interpreted
+    /// as iterations without improvement) and tenure value. It works by evaluating
+    /// each possible swap between a point in the solution and a point outside the solution,
+    /// except the ones that drop a fixed point, add a forbidden one, exceed the capacity
+    /// or take a group out of its limits.
+    /// Then it performs the best swap even if the resulting solution is worse than the
+    /// actual one. Whenever a point scapes the solution it gets marked as tabu and won't
+    /// be able to reenter the solution until a certain number of iterations marked by the
+    /// tabu tenure. The points that enter the solution can also be made tabu to drop, or
+    /// the swap that reverses the move can be made tabu instead. The only exception to
+    /// this is if the resulting solution fulfills an aspiration criteria, which in this
+    /// case is being better than the best solution found since the start. The search
+    /// continues from the memory stored in the state and a checkpoint is saved whenever
+    /// it is due
+    fn perform_search(&mut self, instance: &ProblemInstance, state: &mut TabuSearchState) {
+        loop {
+            // The memory has been created before the search
+            let memory = state.memory.as_mut().unwrap();
+            if memory.iters_without_change >= self.inner_iterations || self.is_stopped() {
+                return;
+            }
+            let entering_index =
+                match self.perform_move(instance, memory, state.moves, &state.frequencies) {
+                    Some(entering_index) => entering_index,
+                    None => return,
+                };
+            if memory.iters_without_change == 0 {
+                if let Some(incumbent) = &self.incumbent {
+                    incumbent.offer_solution(&memory.best_solution);
+                }
+            }
+            state.frequencies[entering_index] += 1;
+            state.moves += 1;
+            if let Some(checkpoint) = self.checkpoint.as_mut() {
+                if checkpoint.is_due(state.moves - 1, state.moves) {
+                    state.word_pos = self.rng.get_word_pos();
+                    checkpoint.save(state.to_checkpoint(instance));
+                }
+            }
+        }
+    }
+
+    /// Whether the incumbent shared with other solvers has been stopped
+    fn is_stopped(&self) -> bool {
+        self.incumbent
+            .as_ref()
+            .is_some_and(|incumbent| incumbent.is_stopped())
+    }
+
+    /// Performs a single move of the tabu search, updating its memory, and gets the
+    /// index of the point that entered the solution. There is no move if every point
+    /// of the solution is fixed or no point outside it can enter. Each swap is evaluated
+    /// by the change of z it causes, without calculating the z of the new solution. The
+    /// best tabu swap is only chosen if it fulfills the aspiration criteria or if every
+    /// swap is tabu
+    fn perform_move(
+        &mut self,
+        instance: &ProblemInstance,
+        memory: &mut SearchMemory,
+        actual_move: usize,
+        frequencies: &[usize],
+    ) -> Option<usize> {
+        let solution = &memory.actual_solution;
+        let indexes = &memory.actual_indexes;
+        let mut movable = vec![true; instance.points.len()];
+        for index in instance
+            .get_fixed_points()
+            .iter()
+            .chain(instance.get_forbidden_points())
+        {
+            movable[*index] = false;
+        }
+        let mut in_solution = vec![false; instance.points.len()];
+        for index in indexes {
+            in_solution[*index] = true;
+        }
+        let z = solution.get_z();
+        let weight = solution.get_weight();
+        let counts = instance.get_group_counts(&solution.points);
+        let (movable, in_solution, counts) = (&movable, &in_solution, &counts);
+        let (tabu_moves, moves): (Vec<_>, Vec<_>) = indexes
+            .iter()
+            .enumerate()
+            .filter(|(_, leaving)| movable[**leaving])
+            .flat_map(|(position, leaving)| {
+                let leaving_point = &instance.points[*leaving];
+                let remaining_weight = weight - leaving_point.weight;
+                (0..instance.points.len())
+                    .filter(move |entering| {
+                        let entering_point = &instance.points[*entering];
+                        !in_solution[*entering]
+                            && movable[*entering]
+                            && instance.fits(remaining_weight + entering_point.weight)
+                            && instance.can_exchange_groups(
+                                counts,
+                                &[leaving_point.group],
+                                &[entering_point.group],
+                            )
+                    })
+                    .map(move |entering| (position, *leaving, entering))
+            })
+            .map(|(position, leaving, entering)| {
+                let new_z = z + get_swap_delta(solution, position, &instance.points[entering]);
+                let tabu = memory.tabu_list.is_tabu(leaving, entering, actual_move);
+                (position, entering, new_z, tabu)
+            })
+            .partition(|(_, _, _, tabu)| *tabu);
+        let best_move = moves
+            .into_iter()
+            .map(|(position, entering, new_z, _)| {
+                let penalty = if self.frequency_penalty == 0.0 || actual_move == 0 {
+                    0.0
+                } else {
+                    self.frequency_penalty * frequencies[entering] as f64 / actual_move as f64
+                };
+                (position, entering, new_z, new_z - penalty)
+            })
+            .reduce(|acc, value| {
+                if value.3 > acc.3 {
+                    return value;
+                }
+                acc
+            });
+        let best_tabu_move = tabu_moves.into_iter().reduce(|acc, value| {
+            if value.2 > acc.2 {
+                return value;
+            }
+            acc
+        });
+        let best_z = memory.best_solution.get_z();
+        let (position, entering) = match (best_move, best_tabu_move) {
+            (Some(best_move), Some(best_tabu_move))
+                if best_tabu_move.2 > best_z && best_tabu_move.2 > best_move.2 =>
+            {
+                (best_tabu_move.0, best_tabu_move.1)
+            }
+            (Some(best_move), _) => (best_move.0, best_move.1),
+            (None, Some(best_tabu_move)) => (best_tabu_move.0, best_tabu_move.1),
+            (None, None) => return None,
+        };
+        let leaving = indexes[position];
+        memory.actual_solution = TabuSearch::get_solution_after_swap(
+            memory.actual_solution.clone(),
+            position,
+            instance.points[entering].clone(),
+        );
+        // The indexes follow the order of the points of the solution
+        memory.actual_indexes.remove(position);
+        memory.actual_indexes.push(entering);
+        // The z is calculated again, so the rounding errors of the deltas don't make
+        // the search believe it has improved when it returns to the best solution
+        if memory.actual_solution.get_z() > best_z {
+            memory.best_solution = memory.actual_solution.clone();
+            memory.iters_without_change = 0;
+        } else {
+            memory.iters_without_change += 1;
+        }
+        let add_tenure = self.draw_tenure(self.add_tenure);
+        if self.pair_tabu {
+            memory
+                .tabu_list
+                .add_pair(leaving, entering, actual_move, add_tenure);
+        } else {
+            let drop_tenure = self.draw_tenure(self.drop_tenure);
+            memory.tabu_list.add_attributes(
+                leaving,
+                entering,
+                actual_move,
+                add_tenure,
+                drop_tenure,
+            );
+        }
+        Some(entering)
+    }
+
+    /// Chooses a tenure at random from the range. The random number generator is
+    /// only used if the range has more than one value
+    fn draw_tenure(&mut self, (min_tenure, max_tenure): (usize, usize)) -> usize {
+        if min_tenure == max_tenure {
+            return min_tenure;
+        }
+        self.rng.gen_range(min_tenure..=max_tenure)
+    }
+
+    fn get_solution_after_swap(
+        mut solution: ProblemSolution,
+        index: usize,
+        point_to_swap: Point,
+    ) -> ProblemSolution {
+        solution.points.remove(index);
+        solution.points.push(point_to_swap);
+        solution
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::problem_solver::{BranchAndBound, GreedySolver, RandomizedGreedySolver};
+    #[test]
+    fn resume_from_checkpoint() {
+        let instance = ProblemInstance::from_file("problem_instances/max_div_20_2.txt").unwrap();
+        let path = std::env::temp_dir().join("daap8_tabu_search_checkpoint.txt");
+        let solution = TabuSearch::new(4, 2, 3, 5)
+            .with_seed(7)
+            .with_checkpoint(&path, 4)
+            .solve(&instance);
+        let state = TabuSearchState::load(&path, &instance).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        let resumed_solution = TabuSearch::new(4, 2, 3, 5)
+            .with_state(state)
+            .solve(&instance);
+        assert_eq!(solution.get_points(), resumed_solution.get_points());
+    }
+    #[test]
+    fn resume_with_seeded_initial_solver() {
+        let instance = ProblemInstance::from_file("problem_instances/max_div_30_2.txt").unwrap();
+        let path = std::env::temp_dir().join("daap8_tabu_search_seeded_checkpoint.txt");
+        let final_path = std::env::temp_dir().join("daap8_tabu_search_seeded_final.txt");
+        let create_solver = |seed| RandomizedGreedySolver::new(4, 30).with_seed(seed);
+        TabuSearch::new(4, 2, 6, 2)
+            .with_seed(3)
+            .with_seeded_initial_solver(create_solver)
+            .with_checkpoint(&final_path, 2)
+            .solve(&instance);
+        let final_state = std::fs::read_to_string(&final_path).unwrap();
+        // A search with a single iteration saves the same states during the first one
+        TabuSearch::new(4, 2, 1, 2)
+            .with_seed(3)
+            .with_seeded_initial_solver(create_solver)
+            .with_checkpoint(&path, 2)
+            .solve(&instance);
+        let state = TabuSearchState::load(&path, &instance).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        TabuSearch::new(4, 2, 6, 2)
+            .with_seeded_initial_solver(create_solver)
+            .with_state(state)
+            .with_checkpoint(&final_path, 2)
+            .solve(&instance);
+        let resumed_final_state = std::fs::read_to_string(&final_path).unwrap();
+        std::fs::remove_file(&final_path).unwrap();
+        assert_eq!(final_state, resumed_final_state);
+    }
+    #[test]
+    fn initial_solution_and_solver() {
+        let instance = ProblemInstance::from_file("problem_instances/max_div_20_2.txt").unwrap();
+        let initial_solution = BranchAndBound::new(4).solve(&instance);
+        let solution = TabuSearch::new(4, 2, 1, 5)
+            .with_initial_solution(initial_solution.clone())
+            .solve(&instance);
+        assert!(solution.get_z() >= initial_solution.get_z());
+        let greedy_solution = GreedySolver::new(4).solve(&instance);
+        let solution = TabuSearch::new(4, 2, 2, 5)
+            .with_initial_solver(GreedySolver::new(4))
+            .solve(&instance);
+        assert!(solution.get_z() >= greedy_solution.get_z());
+    }
+    #[test]
+    fn invalid_initial_solution() {
+        let instance = ProblemInstance::from_file("problem_instances/max_div_20_2.txt").unwrap();
+        let larger_solution = GreedySolver::new(5).solve(&instance);
+        let solution = TabuSearch::new(3, 2, 1, 5)
+            .with_initial_solution(larger_solution)
+            .solve(&instance);
+        assert!(solution.is_feasible(&instance, 3));
+        let other_instance =
+            ProblemInstance::from_file("problem_instances/max_div_15_2.txt").unwrap();
+        let foreign_solution = GreedySolver::new(4).solve(&other_instance);
+        let solution = TabuSearch::new(4, 2, 1, 5)
+            .with_initial_solution(foreign_solution)
+            .solve(&instance);
+        assert!(solution.is_feasible(&instance, 4));
+        assert!(solution.is_from_instance(&instance));
+    }
+    #[test]
+    fn fixed_and_forbidden_points() {
+        let instance = ProblemInstance::from_file("problem_instances/max_div_20_2.txt")
+            .unwrap()
+            .with_fixed_points(vec![0, 5])
+            .with_forbidden_points(vec![1, 2, 3]);
+        let optimum = BranchAndBound::new(4).solve(&instance);
+        for restart_strategy in [
+            RestartStrategy::Construction,
+            RestartStrategy::Diversification,
+        ] {
+            let solution = TabuSearch::new(4, 2, 3, 10)
+                .with_seed(1)
+                .with_restart_strategy(restart_strategy)
+                .solve(&instance);
+            assert!(solution.points.contains(&instance.points[0]));
+            assert!(solution.points.contains(&instance.points[5]));
+            assert!(!solution.points.contains(&instance.points[1]));
+            assert!(solution.get_z() <= optimum.get_z() + 1e-9);
+        }
+    }
+    #[test]
+    fn capacity() {
+        let instance =
+            ProblemInstance::from_file("problem_instances/max_div_15_2_capacity.txt").unwrap();
+        let optimum = BranchAndBound::new(15).solve(&instance);
+        for restart_strategy in [
+            RestartStrategy::Construction,
+            RestartStrategy::Diversification,
+        ] {
+            let solution = TabuSearch::new(15, 2, 3, 10)
+                .with_seed(1)
+                .with_restart_strategy(restart_strategy)
+                .solve(&instance);
+            assert!(solution.is_feasible(&instance, 15));
+            assert!(solution.get_z() <= optimum.get_z() + 1e-9);
+        }
+        // The initial solution can have any number of points that fit
+        let initial_solution = ProblemSolution {
+            points: vec![instance.points[0].clone()],
+        };
+        let solution = TabuSearch::new(15, 2, 3, 10)
+            .with_seed(1)
+            .with_initial_solution(initial_solution.clone())
+            .solve(&instance);
+        assert!(solution.is_feasible(&instance, 15));
+        assert!(solution.get_z() > initial_solution.get_z());
+    }
+    #[test]
+    fn group_limits() {
+        let instance = ProblemInstance::from_file("problem_instances/max_div_20_2_grouped.txt")
+            .unwrap()
+            .with_group_limits(0, 2, 3)
+            .with_group_limits(1, 0, 1);
+        let optimum = BranchAndBound::new(5).solve(&instance);
+        for restart_strategy in [
+            RestartStrategy::Construction,
+            RestartStrategy::Diversification,
+        ] {
+            let solution = TabuSearch::new(5, 2, 3, 10)
+                .with_seed(1)
+                .with_restart_strategy(restart_strategy)
+                .solve(&instance);
+            assert!(solution.is_feasible(&instance, 5));
+            assert!(solution.get_z() <= optimum.get_z() + 1e-9);
+        }
+    }
+    #[test]
+    fn resume_with_random_tenures() {
+        let instance = ProblemInstance::from_file("problem_instances/max_div_20_2.txt").unwrap();
+        for pair_tabu in [false, true] {
+            let path = std::env::temp_dir().join(format!(
+                "daap8_tabu_search_tenure_checkpoint_{}.txt",
+                pair_tabu
+            ));
+            let solver = || {
+                let solver = TabuSearch::new(4, 2, 3, 5)
+                    .with_add_tenure_range(1, 4)
+                    .with_drop_tenure_range(0, 2);
+                if pair_tabu {
+                    return solver.with_pair_tabu();
+                }
+                solver
+            };
+            let solution = solver()
+                .with_seed(3)
+                .with_checkpoint(&path, 6)
+                .solve(&instance);
+            let state = TabuSearchState::load(&path, &instance).unwrap();
+            std::fs::remove_file(&path).unwrap();
+            let resumed_solution = solver().with_state(state).solve(&instance);
+            assert_eq!(solution.get_points(), resumed_solution.get_points());
+        }
+    }
+    #[test]
+    fn resume_with_long_term_memory() {
+        let instance = ProblemInstance::from_file("problem_instances/max_div_20_2.txt").unwrap();
+        let path = std::env::temp_dir().join("daap8_tabu_search_long_term_checkpoint.txt");
+        let solver = || {
+            TabuSearch::new(4, 2, 4, 5)
+                .with_frequency_penalty(1.0)
+                .with_restart_strategy(RestartStrategy::Alternating)
+                .with_elite_pool_size(2)
+        };
+        let solution = solver()
+            .with_seed(11)
+            .with_checkpoint(&path, 7)
+            .solve(&instance);
+        let state = TabuSearchState::load(&path, &instance).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert!(state.elite_solutions.len() <= 2);
+        assert_eq!(state.frequencies.iter().sum::<usize>(), state.moves);
+        let resumed_solution = solver().with_state(state).solve(&instance);
+        assert_eq!(solution.get_points(), resumed_solution.get_points());
+    }
+}
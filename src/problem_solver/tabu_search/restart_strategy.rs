@@ -0,0 +1,16 @@
+/// How a [tabu search](super::TabuSearch) gets the solution each search of the
+/// multiboot starts from. The first search always starts from a constructed solution
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum RestartStrategy {
+    /// Each search starts from a new solution created with GRASP
+    #[default]
+    Construction,
+    /// Each search starts from a random solution of the elite pool, which keeps the
+    /// best solutions found by the previous searches
+    Intensification,
+    /// Each search starts from the solution formed by the points that have entered
+    /// the solution the fewest times, so it explores regions that haven't been visited
+    Diversification,
+    /// The searches alternate between intensification and diversification
+    Alternating,
+}
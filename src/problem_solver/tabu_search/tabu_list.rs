@@ -0,0 +1,82 @@
+use std::collections::BTreeMap;
+
+/// The short term memory of a [tabu search](super::TabuSearch). The attributes
+/// are the indexes of the points in the instance, and for each one it stores the
+/// move until which it can't be added to the solution and the move until which it
+/// can't be dropped from it, so checking if a move is tabu takes constant time.
+/// When whole swaps are tabu it stores the move until which each reversed swap is
+/// tabu instead
+pub(super) struct TabuList {
+    pub(super) add_expiry: Vec<usize>,
+    pub(super) drop_expiry: Vec<usize>,
+    pub(super) pair_expiry: BTreeMap<(usize, usize), usize>,
+}
+
+impl TabuList {
+    /// Creates an empty tabu list for an instance with the specified number of points
+    pub(super) fn new(instance_size: usize) -> Self {
+        TabuList {
+            add_expiry: vec![0; instance_size],
+            drop_expiry: vec![0; instance_size],
+            pair_expiry: BTreeMap::new(),
+        }
+    }
+
+    /// Whether the swap that drops the leaving point and adds the entering one is
+    /// tabu in the specified move
+    pub(super) fn is_tabu(&self, leaving: usize, entering: usize, actual_move: usize) -> bool {
+        actual_move < self.add_expiry[entering]
+            || actual_move < self.drop_expiry[leaving]
+            || self
+                .pair_expiry
+                .get(&(leaving, entering))
+                .is_some_and(|expiry| actual_move < *expiry)
+    }
+
+    /// Makes the leaving point tabu to add and the entering point tabu to drop for
+    /// the specified number of moves after the actual one
+    pub(super) fn add_attributes(
+        &mut self,
+        leaving: usize,
+        entering: usize,
+        actual_move: usize,
+        add_tenure: usize,
+        drop_tenure: usize,
+    ) {
+        self.add_expiry[leaving] = actual_move + 1 + add_tenure;
+        self.drop_expiry[entering] = actual_move + 1 + drop_tenure;
+    }
+
+    /// Makes the swap that reverses the actual one tabu for the specified number of
+    /// moves after the actual one. The swaps that are no longer tabu are forgotten
+    pub(super) fn add_pair(
+        &mut self,
+        leaving: usize,
+        entering: usize,
+        actual_move: usize,
+        tenure: usize,
+    ) {
+        self.pair_expiry.retain(|_, expiry| actual_move < *expiry);
+        self.pair_expiry
+            .insert((entering, leaving), actual_move + 1 + tenure);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn attributes_and_pairs_expire() {
+        let mut tabu_list = TabuList::new(5);
+        tabu_list.add_attributes(0, 1, 10, 2, 1);
+        assert!(tabu_list.is_tabu(2, 0, 12));
+        assert!(!tabu_list.is_tabu(2, 0, 13));
+        assert!(tabu_list.is_tabu(1, 3, 11));
+        assert!(!tabu_list.is_tabu(1, 3, 12));
+        let mut tabu_list = TabuList::new(5);
+        tabu_list.add_pair(0, 1, 10, 2);
+        assert!(tabu_list.is_tabu(1, 0, 12));
+        assert!(!tabu_list.is_tabu(1, 2, 12));
+        assert!(!tabu_list.is_tabu(1, 0, 13));
+    }
+}
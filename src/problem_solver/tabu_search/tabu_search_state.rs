@@ -0,0 +1,177 @@
+use super::super::checkpoint::{
+    solution_to_line, values_to_line, CheckpointError, CheckpointReader,
+};
+use super::{ProblemInstance, ProblemSolution, TabuList};
+use std::path::Path;
+
+/// The state of a [tabu search](super::TabuSearch) saved by a checkpoint. It
+/// contains the iteration of the multiboot, the best solution found in the
+/// previous iterations, the memory of the actual search and the state of the
+/// random number generator. It also contains the long term memory, which is the
+/// number of times each point has entered the solution and the elite solutions
+pub struct TabuSearchState {
+    pub(super) iteration: usize,
+    pub(super) moves: usize,
+    pub(super) seed: u64,
+    pub(super) word_pos: u128,
+    pub(super) best_solution: Option<ProblemSolution>,
+    pub(super) memory: Option<SearchMemory>,
+    pub(super) frequencies: Vec<usize>,
+    pub(super) elite_solutions: Vec<ProblemSolution>,
+}
+
+/// The memory of a single search of the multiboot. The indexes of the points of the
+/// actual solution in the instance are kept in the same order as its points
+pub(super) struct SearchMemory {
+    pub(super) best_solution: ProblemSolution,
+    pub(super) actual_solution: ProblemSolution,
+    pub(super) actual_indexes: Vec<usize>,
+    pub(super) tabu_list: TabuList,
+    pub(super) iters_without_change: usize,
+}
+
+impl SearchMemory {
+    /// Creates the memory of a search that starts from the given solution
+    pub(super) fn new(solution: ProblemSolution, instance: &ProblemInstance) -> Self {
+        SearchMemory {
+            best_solution: solution.clone(),
+            actual_indexes: get_indexes(&solution, instance),
+            actual_solution: solution,
+            tabu_list: TabuList::new(instance.points.len()),
+            iters_without_change: 0,
+        }
+    }
+}
+
+impl TabuSearchState {
+    /// Creates the state of a search that hasn't started yet for an instance with
+    /// the specified number of points
+    pub(super) fn new(seed: u64, instance_size: usize) -> Self {
+        TabuSearchState {
+            iteration: 0,
+            moves: 0,
+            seed,
+            word_pos: 0,
+            best_solution: None,
+            memory: None,
+            frequencies: vec![0; instance_size],
+            elite_solutions: Vec::new(),
+        }
+    }
+
+    /// Loads a state from a file saved by a checkpoint. The instance must be the
+    /// same one the search was solving
+    pub fn load<P: AsRef<Path>>(
+        path: P,
+        instance: &ProblemInstance,
+    ) -> Result<Self, CheckpointError> {
+        let instance_size = instance.points.len();
+        let mut reader = CheckpointReader::from_file(path, instance)?;
+        let iteration = reader.read_value::<usize>()?;
+        let moves = reader.read_value::<usize>()?;
+        let seed = reader.read_value::<u64>()?;
+        let word_pos = reader.read_value::<u128>()?;
+        let best_solution = Some(reader.read_solution()?).filter(|s| !s.points.is_empty());
+        let memory = if reader.read_value::<bool>()? {
+            let best_solution = reader.read_solution()?;
+            let actual_solution = reader.read_solution()?;
+            let add_expiry = reader.read_values::<usize>()?;
+            let drop_expiry = reader.read_values::<usize>()?;
+            if add_expiry.len() != instance_size || drop_expiry.len() != instance_size {
+                return Err(CheckpointError::SyntaxError(reader.get_line()));
+            }
+            let pair_expiry = reader.read_values::<usize>()?;
+            if pair_expiry.len() % 3 != 0 {
+                return Err(CheckpointError::SyntaxError(reader.get_line()));
+            }
+            let pair_expiry = pair_expiry
+                .chunks(3)
+                .map(|values| ((values[0], values[1]), values[2]))
+                .collect();
+            Some(SearchMemory {
+                best_solution,
+                actual_indexes: get_indexes(&actual_solution, instance),
+                actual_solution,
+                tabu_list: TabuList {
+                    add_expiry,
+                    drop_expiry,
+                    pair_expiry,
+                },
+                iters_without_change: reader.read_value::<usize>()?,
+            })
+        } else {
+            None
+        };
+        let frequencies = reader.read_values::<usize>()?;
+        if frequencies.len() != instance_size {
+            return Err(CheckpointError::SyntaxError(reader.get_line()));
+        }
+        let number_of_elite_solutions = reader.read_value::<usize>()?;
+        let mut elite_solutions = Vec::with_capacity(number_of_elite_solutions);
+        for _ in 0..number_of_elite_solutions {
+            elite_solutions.push(reader.read_solution()?);
+        }
+        Ok(TabuSearchState {
+            iteration,
+            moves,
+            seed,
+            word_pos,
+            best_solution,
+            memory,
+            frequencies,
+            elite_solutions,
+        })
+    }
+
+    /// Gets the contents of the file that represents this state. If there isn't
+    /// a best solution yet its line is left empty
+    pub(super) fn to_checkpoint(&self, instance: &ProblemInstance) -> String {
+        let mut lines = vec![
+            self.iteration.to_string(),
+            self.moves.to_string(),
+            self.seed.to_string(),
+            self.word_pos.to_string(),
+            self.best_solution
+                .as_ref()
+                .map(|solution| solution_to_line(solution, instance))
+                .unwrap_or_default(),
+            self.memory.is_some().to_string(),
+        ];
+        if let Some(memory) = &self.memory {
+            lines.push(solution_to_line(&memory.best_solution, instance));
+            lines.push(solution_to_line(&memory.actual_solution, instance));
+            let tabu_list = &memory.tabu_list;
+            lines.push(values_to_line(&tabu_list.add_expiry));
+            lines.push(values_to_line(&tabu_list.drop_expiry));
+            let pair_expiry = tabu_list
+                .pair_expiry
+                .iter()
+                .flat_map(|((leaving, entering), expiry)| [*leaving, *entering, *expiry])
+                .collect::<Vec<usize>>();
+            lines.push(values_to_line(&pair_expiry));
+            lines.push(memory.iters_without_change.to_string());
+        }
+        lines.push(values_to_line(&self.frequencies));
+        lines.push(self.elite_solutions.len().to_string());
+        for solution in &self.elite_solutions {
+            lines.push(solution_to_line(solution, instance));
+        }
+        lines.join("\n") + "\n"
+    }
+}
+
+/// Gets the indexes in the instance of the points of the solution, which always
+/// come from the instance
+fn get_indexes(solution: &ProblemSolution, instance: &ProblemInstance) -> Vec<usize> {
+    solution
+        .points
+        .iter()
+        .map(|point| {
+            instance
+                .points
+                .iter()
+                .position(|instance_point| instance_point == point)
+                .unwrap()
+        })
+        .collect()
+}
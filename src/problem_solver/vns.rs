@@ -0,0 +1,119 @@
+use super::perturbation::perform_random_swaps;
+use super::{
+    local_search::LocalSearch, Incumbent, ProblemInstance, ProblemSolution, ProblemSolver,
+};
+use rand::{rngs::StdRng, SeedableRng};
+
+/// A implementation of a variable neighborhood search. It starts from the solution
+/// of the constructive solver passed to the constructor, improved with the local
+/// search, and in each iteration it shakes the
+/// actual solution by performing k random swaps and improves the result with the
+/// local search. If the improved solution is better the search moves to it and k
+/// goes back to 1, otherwise k is increased until it reaches its maximum value
+pub struct VNS<C: ProblemSolver, L: LocalSearch> {
+    constructive_solver: C,
+    max_k: usize,
+    local_search: L,
+    iterations: usize,
+    rng: StdRng,
+    incumbent: Option<Incumbent>,
+}
+
+impl<C: ProblemSolver, L: LocalSearch> ProblemSolver for VNS<C, L> {
+    fn solve(&mut self, instance: &ProblemInstance) -> ProblemSolution {
+        let solution = self.constructive_solver.solve(instance);
+        let mut best_solution = self.local_search.improve(instance, solution);
+        if let Some(incumbent) = &self.incumbent {
+            incumbent.offer_solution(&best_solution);
+        }
+        let mut k = 1;
+        let mut iters_without_change = 0;
+        while iters_without_change < self.iterations && !self.is_stopped() {
+            let solution = perform_random_swaps(instance, best_solution.clone(), k, &mut self.rng);
+            let solution = self.local_search.improve(instance, solution);
+            if solution.get_z() > best_solution.get_z() {
+                best_solution = solution;
+                if let Some(incumbent) = &self.incumbent {
+                    incumbent.offer_solution(&best_solution);
+                }
+                k = 1;
+                iters_without_change = 0;
+            } else {
+                k = if k == self.max_k { 1 } else { k + 1 };
+                iters_without_change += 1;
+            }
+        }
+        best_solution
+    }
+}
+
+impl<C: ProblemSolver, L: LocalSearch> VNS<C, L> {
+    /// Creates a new VNS with the specified arguments. The solver that creates the
+    /// initial solution, the maximum number of random swaps of the shaking, the local
+    /// search and the number of iterations without improvement before stopping
+    pub fn new(constructive_solver: C, max_k: usize, local_search: L, iterations: usize) -> Self {
+        assert!(max_k > 0);
+        VNS {
+            constructive_solver,
+            max_k,
+            local_search,
+            iterations,
+            rng: StdRng::from_entropy(),
+            incumbent: None,
+        }
+    }
+
+    /// Seeds the random number generator of the shaking, so the solutions are
+    /// reproducible as long as the constructive solver is deterministic or seeded too
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.rng = StdRng::seed_from_u64(seed);
+        self
+    }
+
+    /// Offers the improvements of the search to an [incumbent](super::Incumbent)
+    /// shared with other solvers, and stops as soon as the incumbent is stopped,
+    /// returning the best solution found so far
+    pub fn with_incumbent(mut self, incumbent: Incumbent) -> Self {
+        self.incumbent = Some(incumbent);
+        self
+    }
+
+    /// Whether the incumbent shared with other solvers has been stopped
+    fn is_stopped(&self) -> bool {
+        self.incumbent
+            .as_ref()
+            .is_some_and(|incumbent| incumbent.is_stopped())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::problem_solver::{local_search::Swap, GreedySolver, RandomizedGreedySolver};
+    #[test]
+    fn never_worse_than_the_initial_local_optimum() {
+        let instance = ProblemInstance::from_file("problem_instances/max_div_30_2.txt").unwrap();
+        let initial_solution =
+            Swap::new().improve(&instance, GreedySolver::new(5).solve(&instance));
+        let solution = VNS::new(GreedySolver::new(5), 3, Swap::new(), 10)
+            .with_seed(1)
+            .solve(&instance);
+        assert_eq!(solution.get_points().len(), 5);
+        assert!(solution.get_z() >= initial_solution.get_z());
+    }
+    #[test]
+    fn seeded_search_is_reproducible() {
+        let instance = ProblemInstance::from_file("problem_instances/max_div_20_3.txt").unwrap();
+        let solve = || {
+            VNS::new(
+                RandomizedGreedySolver::new(4, 3).with_seed(2),
+                3,
+                Swap::new(),
+                10,
+            )
+            .with_seed(2)
+            .solve(&instance)
+        };
+        assert_eq!(solve().get_points(), solve().get_points());
+    }
+}